@@ -0,0 +1,84 @@
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// What a part of a puzzle evaluates to.
+///
+/// Integers that fit in an `i64` are always stored as [`Answer::Int`], so that two equal numbers
+/// compare equal whatever type the solver used.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Answer {
+    Int(i64),
+    BigInt(u128),
+    Str(String),
+    /// Rows of a picture, to be read by a human
+    Grid(Vec<String>),
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match i64::try_from(n) {
+                    Ok(n) => Answer::Int(n),
+                    // Only large positive numbers do not fit
+                    Err(_) => Answer::BigInt(n as u128),
+                }
+            }
+        })*
+    };
+}
+
+from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, u128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+/// Answers are serialized as their text, so that every kind fits the same schema.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(Answer::from(37_u32), Answer::from(37_i64));
+        assert_eq!(Answer::from(-3_i32), Answer::Int(-3));
+        let big = u64::MAX as u128 + 1;
+        assert_eq!(Answer::from(big), Answer::BigInt(big));
+        assert_eq!(Answer::from(big).to_string(), "18446744073709551616");
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from("HZKHFEJZ").to_string(), "HZKHFEJZ");
+        let grid = Answer::Grid(vec!["#.".to_string(), ".#".to_string()]);
+        assert_eq!(grid.to_string(), "#.\n.#");
+        assert_eq!(serde_json::to_string(&grid).unwrap(), "\"#.\\n.#\"");
+    }
+}
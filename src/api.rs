@@ -0,0 +1,116 @@
+//! The stable way of embedding the solvers in other programs.
+//!
+//! Everything else in the crate is shaped after the needs of the runner and may change from one
+//! version to the next. The items of this module only change in a semver compatible way:
+//! solving more days is a minor version, and [`SolveError`] may gain variants, so matches on it
+//! need a wildcard arm.
+
+use crate::cancel::panic_message;
+use crate::parse::ParseError;
+use crate::{Answer, Part, PartError};
+use std::error::Error;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Why [`solve`] did not give an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SolveError {
+    /// The day is not solved, see [`available_days`]
+    UnknownDay(u8),
+    /// The day is solved, but not this part of it
+    UnknownPart(u8, Part),
+    /// The input is not one of the puzzle
+    Parse(ParseError),
+    /// The solver panicked, on an input it did not expect
+    Panicked(String),
+    /// The solver could not reach an answer for this input, for the reason given
+    Failed(String),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::UnknownDay(day) => write!(f, "day {} is not solved", day),
+            SolveError::UnknownPart(day, part) => {
+                write!(f, "part {} of day {} is not solved", part, day)
+            }
+            SolveError::Parse(e) => write!(f, "invalid input: {}", e),
+            SolveError::Panicked(message) => write!(f, "solver panicked: {}", message),
+            SolveError::Failed(message) => write!(f, "no answer: {}", message),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolveError::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+/// Solves `part` of `day` of 2021 for `input`, the text of a puzzle input.
+///
+/// A panicking solver is turned into [`SolveError::Panicked`], though the panic hook still
+/// reports it as usual.
+pub fn solve(day: u8, part: Part, input: &str) -> Result<Answer, SolveError> {
+    let solution = crate::solution(2021, day).ok_or(SolveError::UnknownDay(day))?;
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        let parsed = solution.parse(input)?;
+        parsed.solve(part).map_err(|e| match e {
+            PartError::NotImplemented => SolveError::UnknownPart(day, part),
+            PartError::Failed(message) => SolveError::Failed(message),
+        })
+    }))
+    .unwrap_or_else(|payload| Err(SolveError::Panicked(panic_message(payload))))
+}
+
+/// The days [`solve`] accepts, in order.
+pub fn available_days() -> Vec<u8> {
+    (1..=25)
+        .filter(|&day| crate::solution(2021, day).is_some())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        let input = "16,1,2,0,4,2,7,1,2,14";
+        assert_eq!(solve(7, Part::One, input), Ok(Answer::Int(37)));
+        assert_eq!(solve(7, Part::Two, input), Ok(Answer::Int(168)));
+        assert_eq!(solve(19, Part::One, input), Err(SolveError::UnknownDay(19)));
+        let cucumbers = crate::examples::example(2021, 25, Part::One, "58");
+        assert_eq!(
+            solve(25, Part::Two, cucumbers),
+            Err(SolveError::UnknownPart(25, Part::Two))
+        );
+        assert!(matches!(
+            solve(7, Part::One, "16,x"),
+            Err(SolveError::Parse(_))
+        ));
+        // The example of day 13 draws a square, not letters
+        let square = crate::examples::example(2021, 13, Part::One, "17");
+        assert!(matches!(
+            solve(13, Part::Two, square),
+            Err(SolveError::Failed(_))
+        ));
+    }
+
+    #[test]
+    fn days() {
+        let unsolved = [19];
+        let days: Vec<u8> = (1..=25).filter(|day| !unsolved.contains(day)).collect();
+        assert_eq!(available_days(), days);
+    }
+}
@@ -0,0 +1,114 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Asks a solver to stop early. Cloned tokens share their state.
+#[derive(Debug, Clone, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// What [`checkpoint`] unwinds with once the solver is cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+impl Error for Cancelled {}
+
+thread_local! {
+    static TOKEN: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` on this thread with `token` installed, so that the solvers it calls can poll it.
+pub fn with_token<T>(token: Token, f: impl FnOnce() -> T) -> Result<T, Cancelled> {
+    let previous = TOKEN.with(|t| t.replace(Some(token)));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    TOKEN.with(|t| t.replace(previous));
+    match result {
+        Ok(value) => Ok(value),
+        Err(payload) if payload.is::<Cancelled>() => Err(Cancelled),
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Whether the solver running on this thread has been asked to stop. Always false outside of
+/// [`with_token`].
+pub fn is_cancelled() -> bool {
+    TOKEN.with(|t| t.borrow().as_ref().is_some_and(Token::is_cancelled))
+}
+
+/// Stops the solver running on this thread if it has been cancelled, by unwinding back to
+/// [`with_token`]. Meant for the outer loops of long-running solvers.
+pub fn checkpoint() {
+    if is_cancelled() {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+/// The message a panic was raised with, given its payload.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cancelling() {
+        let token = Token::new();
+        assert_eq!(with_token(token.clone(), || 3), Ok(3));
+
+        let result = with_token(token.clone(), || {
+            for i in 0.. {
+                if i == 10 {
+                    token.cancel();
+                }
+                checkpoint();
+            }
+        });
+        assert_eq!(result, Err(Cancelled));
+        assert!(!is_cancelled());
+    }
+
+    #[test]
+    #[should_panic(expected = "boom")]
+    fn other_panics_go_through() {
+        let _ = with_token(Token::new(), || panic!("boom"));
+    }
+
+    #[test]
+    fn panic_messages() {
+        let message = |f: fn()| panic_message(panic::catch_unwind(f).unwrap_err());
+        assert_eq!(message(|| panic!("boom")), "boom");
+        assert_eq!(message(|| panic!("{} booms", 2)), "2 booms");
+        assert_eq!(message(|| panic::panic_any(7)), "unknown panic");
+    }
+}
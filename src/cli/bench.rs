@@ -0,0 +1,60 @@
+use super::selection::Selection;
+use super::stats::Stats;
+use advent_2021::memory::{self, AllocStats};
+use advent_2021::{DynSolution, ParseResult, Part, PartError};
+use std::time::{Duration, Instant};
+
+fn time<T>(runs: usize, mut step: impl FnMut() -> T) -> (Vec<Duration>, Option<T>) {
+    let mut samples = Vec::with_capacity(runs);
+    let mut last = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        last = Some(step());
+        samples.push(start.elapsed());
+    }
+    (samples, last)
+}
+
+/// Runs every step `warmup` times unmeasured, then `iterations` times measured.
+pub fn bench(
+    solution: &dyn DynSolution,
+    input: &str,
+    selection: &Selection,
+    iterations: usize,
+    warmup: usize,
+) -> ParseResult<()> {
+    let day = solution.day();
+    println!("\n day: {} ({} iterations)", day, iterations);
+
+    // Bail out before measuring anything if the input does not parse
+    let parsed = solution.parse(input)?;
+    time(warmup, || solution.parse(input));
+    let (samples, _) = time(iterations.max(1), || solution.parse(input));
+    let (_, allocated) = memory::measure(|| solution.parse(input));
+    report("parse", &samples, allocated);
+
+    for part in selection.parts(day) {
+        let name = match part {
+            Part::One => "part 1",
+            Part::Two => "part 2",
+        };
+        time(warmup, || parsed.solve(part));
+        let (samples, last) = time(iterations.max(1), || parsed.solve(part));
+        if let Some(Err(PartError::NotImplemented)) = last {
+            println!("{:>8}: not implemented", name);
+            continue;
+        }
+        let (_, allocated) = memory::measure(|| parsed.solve(part));
+        report(name, &samples, allocated);
+    }
+    Ok(())
+}
+
+fn report(step: &str, samples: &[Duration], allocated: Option<AllocStats>) {
+    if let Some(stats) = Stats::new(samples) {
+        println!("{:>8}: {}", step, stats);
+    }
+    if let Some(allocated) = allocated {
+        println!("{:>8}  {}", "", allocated);
+    }
+}
@@ -0,0 +1,100 @@
+use advent_2021::generate;
+use anyhow::{bail, Context, Result};
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A seed for runs not given one, different every time.
+pub fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+}
+
+/// Writes a generated input for each of `days` that has a generator to `dayN.txt` in `out`,
+/// refusing to overwrite a file unless `force` is set. Returns the files written.
+pub fn write_inputs(
+    year: u16,
+    days: &BTreeSet<u8>,
+    seed: u64,
+    size: Option<usize>,
+    out: &Path,
+    force: bool,
+) -> Result<Vec<PathBuf>> {
+    let days: Vec<u8> = days
+        .iter()
+        .copied()
+        .filter(|&day| generate::generator(year, day).is_some())
+        .collect();
+    let paths: Vec<PathBuf> = days
+        .iter()
+        .map(|day| out.join(format!("day{}.txt", day)))
+        .collect();
+    if let Some(path) = paths.iter().find(|path| !force && path.exists()) {
+        bail!(
+            "{} already exists, not overwriting it without --force",
+            path.display()
+        );
+    }
+    fs::create_dir_all(out).with_context(|| format!("cannot create {}", out.display()))?;
+    for (&day, path) in days.iter().zip(&paths) {
+        let input = generate::generate(year, day, seed, size).unwrap();
+        fs::write(path, input).with_context(|| format!("cannot write {}", path.display()))?;
+    }
+    Ok(paths)
+}
+
+/// Generates inputs of `days` from `seed`, to stdout for a single day or to files in `out`.
+pub fn generate(
+    year: u16,
+    days: &BTreeSet<u8>,
+    seed: u64,
+    size: Option<usize>,
+    out: Option<&Path>,
+    force: bool,
+) -> Result<()> {
+    if let Some(out) = out {
+        for path in write_inputs(year, days, seed, size, out, force)? {
+            println!("wrote {}", path.display());
+        }
+        return Ok(());
+    }
+    let day = match days.iter().collect::<Vec<_>>()[..] {
+        [&day] => day,
+        _ => bail!("printing needs exactly one day to be selected, or --out to write them all"),
+    };
+    let input = generate::generate(year, day, seed, size)
+        .with_context(|| format!("day {} of {} has no input generator", day, year))?;
+    io::stdout()
+        .write_all(input.as_bytes())
+        .context("cannot print the input")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn writing() {
+        let out = std::env::temp_dir().join(format!("generate-{}", std::process::id()));
+        let days: BTreeSet<u8> = [6, 7, 19].into();
+        let written = write_inputs(2021, &days, 5, Some(10), &out, false);
+        let again = write_inputs(2021, &days, 5, Some(10), &out, false);
+        let forced = write_inputs(2021, &days, 6, Some(10), &out, true);
+        let read = fs::read_to_string(out.join("day7.txt"));
+        fs::remove_dir_all(&out).unwrap();
+
+        assert_eq!(
+            written.unwrap(),
+            [out.join("day6.txt"), out.join("day7.txt")]
+        );
+        assert!(again.unwrap_err().to_string().contains("already exists"));
+        assert_eq!(forced.unwrap().len(), 2);
+        assert_eq!(
+            read.unwrap(),
+            generate::generate(2021, 7, 6, Some(10)).unwrap()
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use super::output::{part_number, Record, Status};
+use super::selection::Selection;
+use super::stats::Stats;
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Answer and timings of one solved part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sample {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse_time_ns: u64,
+    pub solve_time_ns: u64,
+}
+
+impl Sample {
+    fn time(&self) -> Duration {
+        Duration::from_nanos(self.parse_time_ns.saturating_add(self.solve_time_ns))
+    }
+}
+
+/// One invocation of the runner, stored as a line of JSON in the history file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    /// Runs from before years were recorded are all of 2021
+    #[serde(default = "first_year")]
+    pub year: u16,
+    /// `git describe` of the working tree, when run from a repository
+    pub revision: Option<String>,
+    pub timestamp: String,
+    pub samples: Vec<Sample>,
+}
+
+fn first_year() -> u16 {
+    2021
+}
+
+impl Run {
+    /// Keeps the solved parts of `records`, tagged with the current revision and time.
+    pub fn new(year: u16, records: &[Record]) -> Self {
+        let samples = records
+            .iter()
+            .filter(|r| r.status == Status::Ok)
+            .filter_map(|r| {
+                Some(Sample {
+                    day: r.day,
+                    part: r.part,
+                    answer: r.answer.as_ref()?.to_string(),
+                    parse_time_ns: r.parse_time_ns?,
+                    solve_time_ns: r.solve_time_ns?,
+                })
+            })
+            .collect();
+        Run {
+            year,
+            revision: revision(),
+            timestamp: timestamp(SystemTime::now()),
+            samples,
+        }
+    }
+}
+
+fn revision() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8(output.stdout).ok()?.trim().to_string())
+}
+
+/// Formats `time` in UTC, like `2021-12-25T06:00:00Z`.
+fn timestamp(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, secs) = (secs / 86400, secs % 86400);
+    // Days since the epoch to a civil date, from http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+pub fn append(path: &Path, run: &Run) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("cannot open history file {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(run)?)
+        .with_context(|| format!("cannot write history file {}", path.display()))
+}
+
+pub fn load(path: &Path) -> Result<Vec<Run>> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("cannot read history file {}", path.display()))?;
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("{}:{}: invalid history entry", path.display(), i + 1))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Slower { latest: Duration, median: Duration },
+    Answer { previous: String, latest: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Flag {
+    pub day: u8,
+    pub part: u8,
+    pub change: Change,
+}
+
+/// Compares the last run to the `window` runs before it. A part is flagged when its parse and
+/// solve time is more than `threshold` percent above their median, or when its answer differs
+/// from the most recent earlier one.
+pub fn compare(runs: &[Run], window: usize, threshold: f64) -> Vec<Flag> {
+    let (latest, earlier) = match runs.split_last() {
+        Some(split) => split,
+        None => return Vec::new(),
+    };
+    let earlier = &earlier[earlier.len().saturating_sub(window)..];
+    let mut previous: BTreeMap<(u8, u8), Vec<&Sample>> = BTreeMap::new();
+    for sample in earlier.iter().flat_map(|run| &run.samples) {
+        previous
+            .entry((sample.day, sample.part))
+            .or_default()
+            .push(sample);
+    }
+
+    let mut flags = Vec::new();
+    for sample in &latest.samples {
+        let before = match previous.get(&(sample.day, sample.part)) {
+            Some(before) => before,
+            None => continue,
+        };
+        let flag = |change| Flag {
+            day: sample.day,
+            part: sample.part,
+            change,
+        };
+        let last = before.last().unwrap();
+        if last.answer != sample.answer {
+            flags.push(flag(Change::Answer {
+                previous: last.answer.clone(),
+                latest: sample.answer.clone(),
+            }));
+        }
+        let times: Vec<Duration> = before.iter().map(|s| s.time()).collect();
+        let median = Stats::new(&times).unwrap().median;
+        if sample.time().as_secs_f64() > median.as_secs_f64() * (1.0 + threshold / 100.0) {
+            flags.push(flag(Change::Slower {
+                latest: sample.time(),
+                median,
+            }));
+        }
+    }
+    flags
+}
+
+/// Prints the flagged parts of the selection and tells whether there were none.
+pub fn print_compare(
+    runs: &[Run],
+    selection: &Selection,
+    window: usize,
+    threshold: f64,
+) -> Result<bool> {
+    if runs.len() < 2 {
+        bail!(
+            "the history needs at least two runs to compare, got {}",
+            runs.len()
+        );
+    }
+    let latest = runs.last().unwrap();
+    let compared = window.min(runs.len() - 1);
+    println!(
+        "run of {} ({}) against {} earlier runs",
+        latest.timestamp,
+        latest.revision.as_deref().unwrap_or("unknown revision"),
+        compared
+    );
+    let selected = |flag: &Flag| {
+        let parts = selection.parts(flag.day);
+        parts.into_iter().any(|p| part_number(p) == flag.part)
+    };
+    let flags: Vec<Flag> = compare(runs, window, threshold)
+        .into_iter()
+        .filter(selected)
+        .collect();
+    for flag in &flags {
+        let name = format!("day {} part {}", flag.day, flag.part);
+        match &flag.change {
+            Change::Slower { latest, median } => {
+                let increase = (latest.as_secs_f64() / median.as_secs_f64() - 1.0) * 100.0;
+                println!(
+                    "{}: {} {:.2?}, median {:.2?} (+{:.0}%)",
+                    name,
+                    "SLOWER".red(),
+                    latest,
+                    median,
+                    increase
+                );
+            }
+            Change::Answer { previous, latest } => println!(
+                "{}: {} from {:?} to {:?}",
+                name,
+                "ANSWER CHANGED".red(),
+                previous,
+                latest
+            ),
+        }
+    }
+    if flags.is_empty() {
+        println!("{}", "no regressions".green());
+    }
+    Ok(flags.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn run(samples: &[(u8, &str, u64)]) -> Run {
+        Run {
+            year: 2021,
+            revision: None,
+            timestamp: String::new(),
+            samples: samples
+                .iter()
+                .map(|&(day, answer, ns)| Sample {
+                    day,
+                    part: 1,
+                    answer: answer.to_string(),
+                    parse_time_ns: 0,
+                    solve_time_ns: ns,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn flags() {
+        let runs = [
+            run(&[(1, "7", 1000), (2, "3", 100)]),
+            run(&[(1, "7", 5000), (2, "3", 100)]),
+            run(&[(1, "7", 1000), (2, "3", 100)]),
+            run(&[(1, "7", 1050), (2, "4", 300), (3, "1", 10)]),
+        ];
+        let flags = compare(&runs, 5, 10.0);
+        let changes: Vec<_> = flags.iter().map(|f| (f.day, &f.change)).collect();
+        let ns = Duration::from_nanos;
+        assert_eq!(
+            changes,
+            [
+                (
+                    2,
+                    &Change::Answer {
+                        previous: "3".to_string(),
+                        latest: "4".to_string()
+                    }
+                ),
+                (
+                    2,
+                    &Change::Slower {
+                        latest: ns(300),
+                        median: ns(100)
+                    }
+                )
+            ]
+        );
+        // Only the run just before, where day 1 took 1000ns
+        assert!(compare(&runs, 1, 1.0).iter().any(|f| f.day == 1));
+    }
+
+    #[test]
+    fn timestamps() {
+        let at = |secs| timestamp(UNIX_EPOCH + Duration::from_secs(secs));
+        assert_eq!(at(0), "1970-01-01T00:00:00Z");
+        assert_eq!(at(1640412000), "2021-12-25T06:00:00Z");
+        assert_eq!(at(951782400), "2000-02-29T00:00:00Z");
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = std::env::temp_dir().join(format!("history-{}.jsonl", std::process::id()));
+        let runs = [run(&[(1, "7", 10)]), run(&[(1, "a\"b", 20)])];
+        runs.iter().for_each(|r| append(&path, r).unwrap());
+        let loaded = load(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), runs);
+    }
+}
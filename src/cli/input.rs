@@ -0,0 +1,137 @@
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where puzzle inputs are read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `dayN.txt` files in a directory
+    Dir(PathBuf),
+    /// A single file, only usable for a single day
+    File(PathBuf),
+    Stdin,
+    /// The `n`th example of the puzzle texts, counting from 1
+    Example {
+        year: u16,
+        n: usize,
+    },
+    /// The body of a request to the server
+    Body(String),
+}
+
+impl Source {
+    /// `input` wins over `dir` when both are given, `-` meaning stdin.
+    pub fn new(input: Option<&Path>, dir: &Path) -> Self {
+        match input {
+            Some(path) if path == Path::new("-") => Source::Stdin,
+            Some(path) => Source::File(path.to_owned()),
+            None => Source::Dir(dir.to_owned()),
+        }
+    }
+
+    /// Single file sources cannot feed more than one day.
+    pub fn check_days(&self, days: usize) -> Result<()> {
+        if !matches!(self, Source::Dir(_) | Source::Example { .. }) && days != 1 {
+            bail!("--input needs exactly one day to be selected, got {}", days);
+        }
+        Ok(())
+    }
+
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            Source::Dir(dir) => Some(dir.join(format!("day{}.txt", day))),
+            Source::File(path) => Some(path.clone()),
+            Source::Stdin | Source::Example { .. } | Source::Body(_) => None,
+        }
+    }
+
+    /// How the input of `day` is named in diagnostics.
+    pub fn name(&self, day: u8) -> String {
+        match self {
+            Source::Example { n, .. } => format!("<example {} of day {}>", n, day),
+            Source::Body(_) => "<request body>".to_string(),
+            _ => self
+                .path(day)
+                .map_or_else(|| "<stdin>".to_string(), |path| path.display().to_string()),
+        }
+    }
+
+    /// Why this source has no input for `day`, when that is expected rather than an error: not
+    /// every puzzle text has as many examples as others.
+    pub fn skipped(&self, day: u8) -> Option<String> {
+        match self {
+            Source::Example { year, n } if advent_2021::examples(*year, day).len() < *n => {
+                Some(format!("day {} has no example {}", day, n))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String> {
+        if let Source::Example { year, n } = self {
+            let example = n
+                .checked_sub(1)
+                .and_then(|i| advent_2021::examples(*year, day).get(i))
+                .with_context(|| format!("day {} has no example {}", day, n))?;
+            return Ok(example.input.to_string());
+        }
+        if let Source::Body(input) = self {
+            return Ok(input.clone());
+        }
+        match self.path(day) {
+            Some(path) => fs::read_to_string(&path).with_context(|| {
+                format!("cannot read input for day {} at {}", day, path.display())
+            }),
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("cannot read input from stdin")?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn paths() {
+        let dir = Path::new("input/2021");
+        let source = Source::new(None, dir);
+        assert_eq!(source.path(3), Some(PathBuf::from("input/2021/day3.txt")));
+        assert!(source.check_days(25).is_ok());
+
+        let source = Source::new(Some(Path::new("mine.txt")), dir);
+        assert_eq!(source.path(3), Some(PathBuf::from("mine.txt")));
+        assert!(source.check_days(1).is_ok());
+        assert!(source.check_days(2).is_err());
+
+        let source = Source::new(Some(Path::new("-")), dir);
+        assert_eq!(source, Source::Stdin);
+        assert_eq!(source.path(3), None);
+    }
+
+    #[test]
+    fn examples() {
+        let source = Source::Example { year: 2021, n: 2 };
+        assert!(source.check_days(25).is_ok());
+        assert!(source.read(12).unwrap().starts_with("dc-end\n"));
+        assert_eq!(source.name(12), "<example 2 of day 12>");
+        assert_eq!(source.skipped(12), None);
+        let err = source.read(7).unwrap_err().to_string();
+        assert_eq!(err, "day 7 has no example 2");
+        assert_eq!(source.skipped(7).as_deref(), Some("day 7 has no example 2"));
+        assert!(Source::Stdin.skipped(7).is_none());
+    }
+
+    #[test]
+    fn missing_file_names_the_path() {
+        let source = Source::new(None, Path::new("does/not/exist"));
+        let err = source.read(4).unwrap_err().to_string();
+        assert!(err.contains("does/not/exist/day4.txt"), "{}", err);
+    }
+}
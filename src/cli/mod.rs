@@ -0,0 +1,14 @@
+pub mod bench;
+pub mod generate;
+pub mod history;
+pub mod input;
+pub mod output;
+pub mod pool;
+pub mod run;
+pub mod scaffold;
+pub mod selection;
+pub mod serve;
+pub mod stats;
+pub mod verify;
+pub mod watch;
+pub mod watchdog;
@@ -0,0 +1,285 @@
+use advent_2021::memory::AllocStats;
+use advent_2021::{Answer, Part};
+use clap::ArgEnum;
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ArgEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    NotImplemented,
+    /// The source has no input for the day, like the examples of a puzzle text without any
+    Skipped,
+    Error,
+    Timeout,
+}
+
+/// The outcome of one part of one day. Every field is always present so that consumers get the
+/// same schema whether or not the day is solved.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    pub error: Option<String>,
+    pub parse_time_ns: Option<u64>,
+    pub solve_time_ns: Option<u64>,
+    /// Only measured with the `alloc-stats` feature
+    pub parse_memory: Option<AllocStats>,
+    pub solve_memory: Option<AllocStats>,
+}
+
+fn nanos(d: Duration) -> u64 {
+    d.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+impl Record {
+    pub fn solved(day: u8, part: Part, answer: Answer, parse: Duration, solve: Duration) -> Self {
+        Record {
+            day,
+            part: part_number(part),
+            status: Status::Ok,
+            answer: Some(answer),
+            error: None,
+            parse_time_ns: Some(nanos(parse)),
+            solve_time_ns: Some(nanos(solve)),
+            parse_memory: None,
+            solve_memory: None,
+        }
+    }
+
+    pub fn with_memory(self, parse: Option<AllocStats>, solve: Option<AllocStats>) -> Self {
+        Record {
+            parse_memory: parse,
+            solve_memory: solve,
+            ..self
+        }
+    }
+
+    pub fn not_implemented(day: u8, part: Part) -> Self {
+        Record {
+            day,
+            part: part_number(part),
+            status: Status::NotImplemented,
+            answer: None,
+            error: None,
+            parse_time_ns: None,
+            solve_time_ns: None,
+            parse_memory: None,
+            solve_memory: None,
+        }
+    }
+
+    pub fn skipped(day: u8, part: Part) -> Self {
+        Record {
+            status: Status::Skipped,
+            ..Record::not_implemented(day, part)
+        }
+    }
+
+    pub fn timeout(day: u8, part: Part, parse: Duration, after: Duration) -> Self {
+        Record {
+            day,
+            part: part_number(part),
+            status: Status::Timeout,
+            answer: None,
+            error: Some(format!("timed out after {:?}", after)),
+            parse_time_ns: Some(nanos(parse)),
+            solve_time_ns: None,
+            parse_memory: None,
+            solve_memory: None,
+        }
+    }
+
+    /// Whether the part should have been answered but was not.
+    pub fn failed(&self) -> bool {
+        matches!(self.status, Status::Error | Status::Timeout)
+    }
+
+    pub fn error(day: u8, part: Part, error: String) -> Self {
+        Record {
+            day,
+            part: part_number(part),
+            status: Status::Error,
+            answer: None,
+            error: Some(error),
+            parse_time_ns: None,
+            solve_time_ns: None,
+            parse_memory: None,
+            solve_memory: None,
+        }
+    }
+}
+
+pub fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+const CSV_HEADER: &str = "day,part,status,answer,error,parse_time_ns,solve_time_ns,\
+    parse_allocations,parse_bytes,parse_peak_bytes,solve_allocations,solve_bytes,solve_peak_bytes";
+
+fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn csv_row(r: &Record) -> String {
+    let opt = |n: Option<u64>| n.map(|n| n.to_string()).unwrap_or_default();
+    let memory = |m: Option<AllocStats>| {
+        let field = |f: fn(&AllocStats) -> u64| opt(m.as_ref().map(f));
+        format!(
+            "{},{},{}",
+            field(|m| m.allocations),
+            field(|m| m.bytes),
+            field(|m| m.peak_bytes)
+        )
+    };
+    let status = serde_json::to_value(r.status).unwrap();
+    format!(
+        "{},{},{},{},{},{},{},{},{}",
+        r.day,
+        r.part,
+        status.as_str().unwrap(),
+        csv_field(&r.answer.as_ref().map(Answer::to_string).unwrap_or_default()),
+        csv_field(r.error.as_deref().unwrap_or_default()),
+        opt(r.parse_time_ns),
+        opt(r.solve_time_ns),
+        memory(r.parse_memory),
+        memory(r.solve_memory),
+    )
+}
+
+/// Prints records as days are run. JSON output is a single array, so it is only written by
+/// [`Report::finish`].
+pub struct Report {
+    format: Format,
+    records: Vec<Record>,
+}
+
+impl Report {
+    pub fn new(format: Format) -> Self {
+        if format == Format::Csv {
+            println!("{}", CSV_HEADER);
+        }
+        Report {
+            format,
+            records: Vec::new(),
+        }
+    }
+
+    pub fn push_day(&mut self, day: u8, records: Vec<Record>) {
+        match self.format {
+            Format::Text => print_text(day, &records),
+            Format::Csv => records.iter().for_each(|r| println!("{}", csv_row(r))),
+            Format::Json => {}
+        }
+        self.records.extend(records);
+    }
+
+    pub fn finish(self) -> Vec<Record> {
+        if self.format == Format::Json {
+            println!("{}", serde_json::to_string_pretty(&self.records).unwrap());
+        }
+        self.records
+    }
+}
+
+fn print_text(day: u8, records: &[Record]) {
+    println!("\n day: {}", day);
+    let mut total = Duration::ZERO;
+    if let Some(parse) = records.iter().find_map(|r| r.parse_time_ns) {
+        total += Duration::from_nanos(parse);
+    }
+    if let Some(memory) = records.iter().find_map(|r| r.parse_memory) {
+        println!("memory parse: {}", memory);
+    }
+    for r in records {
+        let name = if r.part == 1 { "first" } else { "second" };
+        match r.status {
+            Status::Ok => match &r.answer {
+                // Pictures start on their own line to keep their rows aligned
+                Some(answer @ Answer::Grid(_)) => println!("{}:\n{}", name, answer),
+                Some(answer) => println!("{}: {}", name, answer),
+                None => println!("{}:", name),
+            },
+            Status::NotImplemented => println!("{}: not implemented", name),
+            Status::Skipped => println!("{}: skipped", name),
+            Status::Timeout => println!("{}: TIMEOUT", name),
+            Status::Error => println!(
+                "{}: error: {}",
+                name,
+                r.error.as_deref().unwrap_or_default()
+            ),
+        }
+        if let Some(memory) = r.solve_memory {
+            println!("memory {}: {}", name, memory);
+        }
+        total += Duration::from_nanos(r.solve_time_ns.unwrap_or(0));
+    }
+    println!("total time: {:?}", total);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn csv() {
+        let r = Record::solved(
+            13,
+            Part::Two,
+            Answer::Grid(vec!["#.".to_string(), "\"#".to_string()]),
+            Duration::from_nanos(5),
+            Duration::from_nanos(7),
+        );
+        assert_eq!(csv_row(&r), "13,2,ok,\"#.\n\"\"#\",,5,7,,,,,,");
+        let stats = AllocStats {
+            allocations: 2,
+            bytes: 30,
+            peak_bytes: 20,
+        };
+        let r = r.with_memory(None, Some(stats));
+        assert_eq!(csv_row(&r), "13,2,ok,\"#.\n\"\"#\",,5,7,,,,2,30,20");
+        let r = Record::not_implemented(19, Part::One);
+        assert_eq!(csv_row(&r), "19,1,not_implemented,,,,,,,,,,");
+        assert_eq!(
+            CSV_HEADER.split(',').count(),
+            csv_row(&r).split(',').count()
+        );
+    }
+
+    #[test]
+    fn json_schema_is_stable() {
+        let solved = serde_json::to_value(Record::solved(
+            1,
+            Part::One,
+            Answer::Int(7),
+            Duration::ZERO,
+            Duration::ZERO,
+        ))
+        .unwrap();
+        let missing = serde_json::to_value(Record::not_implemented(19, Part::One)).unwrap();
+        let error = serde_json::to_value(Record::error(3, Part::Two, "oops".into())).unwrap();
+        let keys =
+            |v: &serde_json::Value| v.as_object().unwrap().keys().cloned().collect::<Vec<_>>();
+        assert_eq!(keys(&solved), keys(&missing));
+        assert_eq!(keys(&solved), keys(&error));
+        assert_eq!(missing["status"], "not_implemented");
+        assert!(missing["answer"].is_null());
+    }
+}
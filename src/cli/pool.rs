@@ -0,0 +1,99 @@
+use std::collections::VecDeque;
+use std::sync::{Condvar, Mutex};
+use std::thread;
+
+type Job<'scope> = Box<dyn FnOnce(&Pool<'scope>) + Send + 'scope>;
+
+/// A fixed number of threads running jobs, which may push more jobs while they run.
+pub struct Pool<'scope> {
+    state: Mutex<State<'scope>>,
+    changed: Condvar,
+}
+
+struct State<'scope> {
+    queue: VecDeque<Job<'scope>>,
+    /// Jobs queued or running, the pool is done once there are none left
+    pending: usize,
+}
+
+/// Marks a job as finished even if it panics, so that the other workers do not wait for it
+/// forever.
+struct Finished<'a, 'scope>(&'a Pool<'scope>);
+
+impl Drop for Finished<'_, '_> {
+    fn drop(&mut self) {
+        let mut state = self.0.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.pending -= 1;
+        if state.pending == 0 {
+            self.0.changed.notify_all();
+        }
+    }
+}
+
+impl<'scope> Pool<'scope> {
+    /// Runs the jobs pushed by `start`, and the ones they push in turn, on `threads` threads.
+    /// Returns once all of them are done.
+    pub fn run(threads: usize, start: impl FnOnce(&Pool<'scope>)) {
+        let pool = Pool {
+            state: Mutex::new(State {
+                queue: VecDeque::new(),
+                pending: 0,
+            }),
+            changed: Condvar::new(),
+        };
+        start(&pool);
+        thread::scope(|s| {
+            for _ in 0..threads.max(1) {
+                s.spawn(|| pool.work());
+            }
+        });
+    }
+
+    pub fn push(&self, job: impl FnOnce(&Pool<'scope>) + Send + 'scope) {
+        let mut state = self.state.lock().unwrap();
+        state.queue.push_back(Box::new(job));
+        state.pending += 1;
+        self.changed.notify_one();
+    }
+
+    fn work(&self) {
+        loop {
+            let job = {
+                let mut state = self.state.lock().unwrap();
+                loop {
+                    if let Some(job) = state.queue.pop_front() {
+                        break job;
+                    }
+                    if state.pending == 0 {
+                        return;
+                    }
+                    state = self.changed.wait(state).unwrap();
+                }
+            };
+            let _finished = Finished(self);
+            job(self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn nested_jobs() {
+        let count = AtomicUsize::new(0);
+        Pool::run(3, |pool| {
+            for _ in 0..10 {
+                pool.push(|pool| {
+                    count.fetch_add(1, Ordering::Relaxed);
+                    pool.push(|_| {
+                        count.fetch_add(1, Ordering::Relaxed);
+                    });
+                });
+            }
+        });
+        assert_eq!(count.into_inner(), 20);
+    }
+}
@@ -0,0 +1,273 @@
+use super::input::Source;
+use super::output::Record;
+use super::pool::Pool;
+use super::selection::Selection;
+use super::watchdog::{self, Outcome};
+use advent_2021::cancel;
+use advent_2021::memory::{self, AllocStats};
+use advent_2021::{DynSolution, Parsed, Part, PartError};
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long parsing took and what it allocated.
+#[derive(Clone, Copy)]
+struct ParseCost {
+    time: Duration,
+    memory: Option<AllocStats>,
+}
+
+/// Reads and parses the input of `day`, or gives the records of its failed `parts`. A panicking
+/// parser is reported as an error, so that it does not take a worker of the pool down with it.
+fn prepare(
+    solution: Option<&dyn DynSolution>,
+    day: u8,
+    parts: &[Part],
+    source: &Source,
+) -> Result<(Arc<dyn Parsed>, ParseCost), Vec<Record>> {
+    let fail = |record: &dyn Fn(Part) -> Record| parts.iter().map(|&part| record(part)).collect();
+    let solution = match solution {
+        Some(solution) => solution,
+        None => return Err(fail(&|part| Record::not_implemented(day, part))),
+    };
+    if source.skipped(day).is_some() {
+        return Err(fail(&|part| Record::skipped(day, part)));
+    }
+    let input = match source.read(day) {
+        Ok(input) => input,
+        Err(e) => return Err(fail(&|part| Record::error(day, part, format!("{:#}", e)))),
+    };
+    let time_start = Instant::now();
+    let (parsed, memory) =
+        memory::measure(|| panic::catch_unwind(AssertUnwindSafe(|| solution.parse(&input))));
+    let cost = ParseCost {
+        time: time_start.elapsed(),
+        memory,
+    };
+    let message = match parsed {
+        Ok(Ok(parsed)) => return Ok((parsed.into(), cost)),
+        Ok(Err(e)) => format!("{}: {}", source.name(day), e),
+        Err(payload) => format!(
+            "{}: panicked: {}",
+            source.name(day),
+            cancel::panic_message(payload)
+        ),
+    };
+    Err(fail(&|part| Record::error(day, part, message.clone())))
+}
+
+/// Solves `part`, giving up after `timeout` if there is one. A panicking solver is reported as
+/// an error either way.
+fn solve(
+    parsed: &Arc<dyn Parsed>,
+    day: u8,
+    part: Part,
+    parse: ParseCost,
+    timeout: Option<Duration>,
+) -> Record {
+    let time_start = Instant::now();
+    // Measured on the thread that solves, allocations being counted per thread
+    let outcome = match timeout {
+        None => {
+            match panic::catch_unwind(AssertUnwindSafe(|| memory::measure(|| parsed.solve(part)))) {
+                Ok(solved) => Outcome::Done(solved),
+                Err(payload) => Outcome::Panicked(cancel::panic_message(payload)),
+            }
+        }
+        Some(timeout) => {
+            let parsed = Arc::clone(parsed);
+            watchdog::watch(timeout, move || memory::measure(|| parsed.solve(part)))
+        }
+    };
+    match outcome {
+        Outcome::Done((Ok(answer), memory)) => {
+            Record::solved(day, part, answer, parse.time, time_start.elapsed())
+                .with_memory(parse.memory, memory)
+        }
+        Outcome::Done((Err(PartError::NotImplemented), _)) => Record::not_implemented(day, part),
+        Outcome::Done((Err(e), _)) => Record::error(day, part, e.to_string()),
+        Outcome::TimedOut => Record::timeout(day, part, parse.time, timeout.unwrap_or_default())
+            .with_memory(parse.memory, None),
+        Outcome::Panicked(message) => Record::error(day, part, format!("panicked: {}", message)),
+    }
+}
+
+pub fn run(
+    solution: Option<&dyn DynSolution>,
+    day: u8,
+    selection: &Selection,
+    source: &Source,
+    timeout: Option<Duration>,
+) -> Vec<Record> {
+    let parts = selection.parts(day);
+    match prepare(solution, day, &parts, source) {
+        Ok((parsed, parse)) => parts
+            .into_iter()
+            .map(|part| solve(&parsed, day, part, parse, timeout))
+            .collect(),
+        Err(records) => records,
+    }
+}
+
+/// Runs every selected day on `jobs` threads. Once a day is parsed, its parts are solved
+/// independently. Records come back grouped by day, in order.
+pub fn run_parallel(
+    year: u16,
+    selection: &Selection,
+    source: &Source,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<(u8, Vec<Record>)> {
+    let records = Mutex::new(BTreeMap::new());
+    let records_ref = &records;
+    Pool::run(jobs, |pool| {
+        for day in selection.days() {
+            pool.push(move |pool| {
+                let parts = selection.parts(day);
+                let solution = advent_2021::solution(year, day);
+                match prepare(solution, day, &parts, source) {
+                    Ok((parsed, parse)) => {
+                        for part in parts {
+                            let parsed = Arc::clone(&parsed);
+                            pool.push(move |_| {
+                                let record = solve(&parsed, day, part, parse, timeout);
+                                records_ref
+                                    .lock()
+                                    .unwrap()
+                                    .insert((day, record.part), record);
+                            });
+                        }
+                    }
+                    Err(failed) => {
+                        let mut records = records_ref.lock().unwrap();
+                        records.extend(failed.into_iter().map(|r| ((day, r.part), r)));
+                    }
+                }
+            });
+        }
+    });
+
+    let mut days: Vec<(u8, Vec<Record>)> = Vec::new();
+    for ((day, _), record) in records.into_inner().unwrap() {
+        match days.last_mut() {
+            Some((last, records)) if *last == day => records.push(record),
+            _ => days.push((day, vec![record])),
+        }
+    }
+    days
+}
+
+/// Time spent parsing and solving, as if everything had run on a single thread.
+pub fn cpu_time(records: &[Record]) -> Duration {
+    let mut parsed = BTreeMap::new();
+    let mut total = Duration::ZERO;
+    for r in records {
+        if let Some(parse) = r.parse_time_ns {
+            parsed.insert(r.day, parse);
+        }
+        total += Duration::from_nanos(r.solve_time_ns.unwrap_or(0));
+    }
+    let parse_time: Duration = parsed.into_values().map(Duration::from_nanos).sum();
+    total + parse_time
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cli::output::Status;
+    use crate::cli::selection::Days;
+    use advent_2021::{ParseResult, PartResult};
+    use std::path::Path;
+
+    #[test]
+    fn parallel_records_are_in_order() {
+        let days: Days = "17-20".parse().unwrap();
+        let selection = Selection::new(Some(&days), None, &[]);
+        let source = Source::new(None, Path::new("no/such/dir"));
+        let results = run_parallel(2021, &selection, &source, 4, None);
+
+        let order: Vec<_> = results
+            .iter()
+            .flat_map(|(day, records)| records.iter().map(move |r| (*day, r.part)))
+            .collect();
+        let expected: Vec<_> = (17..=20).flat_map(|d| [(d, 1), (d, 2)]).collect();
+        assert_eq!(order, expected);
+        assert_eq!(results[2].1[0].status, Status::NotImplemented);
+        assert_eq!(results[3].1[1].status, Status::Error);
+    }
+
+    #[test]
+    fn days_without_examples_are_skipped() {
+        let days: Days = "24-25".parse().unwrap();
+        let selection = Selection::new(Some(&days), Some(Part::One), &[]);
+        let source = Source::Example { year: 2021, n: 1 };
+        let results = run_parallel(2021, &selection, &source, 2, None);
+        let statuses: Vec<_> = results.iter().map(|(_, r)| r[0].status).collect();
+        assert_eq!(statuses, [Status::Skipped, Status::Ok]);
+    }
+
+    struct Panicking;
+
+    impl Parsed for Panicking {
+        fn first(&self) -> PartResult {
+            panic!("oops")
+        }
+
+        fn second(&self) -> PartResult {
+            Ok(2.into())
+        }
+    }
+
+    #[test]
+    fn panics_are_errors() {
+        let parsed: Arc<dyn Parsed> = Arc::new(Panicking);
+        let parse = ParseCost {
+            time: Duration::ZERO,
+            memory: None,
+        };
+        for timeout in [None, Some(Duration::from_secs(10))] {
+            let record = solve(&parsed, 1, Part::One, parse, timeout);
+            assert_eq!(record.status, Status::Error);
+            assert_eq!(record.error.as_deref(), Some("panicked: oops"));
+            let record = solve(&parsed, 1, Part::Two, parse, timeout);
+            assert_eq!(record.status, Status::Ok);
+        }
+    }
+
+    struct PanickingParser;
+
+    impl DynSolution for PanickingParser {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn parse(&self, _input: &str) -> ParseResult<Box<dyn Parsed>> {
+            panic!("oops")
+        }
+    }
+
+    #[test]
+    fn parser_panics_are_errors() {
+        let source = Source::Example { year: 2021, n: 1 };
+        let records = match prepare(Some(&PanickingParser), 1, &Part::BOTH, &source) {
+            Ok(_) => panic!("the parser cannot succeed"),
+            Err(records) => records,
+        };
+        for record in records {
+            assert_eq!(record.status, Status::Error);
+            assert!(record.error.unwrap().ends_with("panicked: oops"));
+        }
+    }
+
+    #[test]
+    fn cpu_time_counts_parsing_once() {
+        let ms = Duration::from_millis;
+        let records = [
+            Record::solved(1, Part::One, 1.into(), ms(5), ms(1)),
+            Record::solved(1, Part::Two, 2.into(), ms(5), ms(2)),
+            Record::not_implemented(19, Part::One),
+        ];
+        assert_eq!(cpu_time(&records), ms(8));
+    }
+}
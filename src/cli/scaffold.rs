@@ -0,0 +1,373 @@
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Starting point of a day, `{year}` and `{day}` standing for their numbers. Its tests fail
+/// until the example of the puzzle text is in the examples table and the day is solved.
+const TEMPLATE: &str = r#"use crate::parse::{self, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+
+pub fn gen(input: &str) -> ParseResult<Vec<i64>> {
+    input
+        .lines()
+        .map(|line| parse::number(input, line.trim()))
+        .collect()
+}
+
+pub fn first(_input: &[i64]) -> i64 {
+    0
+}
+
+pub fn second(_input: &[i64]) -> i64 {
+    0
+}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u8 = {day};
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{examples, Example};
+
+    // The example of the puzzle text, whose input and answers go in src/y{year}/examples.rs
+    fn example() -> Example {
+        examples({year}, {day})[0]
+    }
+
+    #[test]
+    fn one() {
+        let example = example();
+        let answer = first(&gen(example.input).unwrap()).to_string();
+        assert_eq!(Some(answer.as_str()), example.first);
+    }
+
+    #[test]
+    fn two() {
+        let example = example();
+        let answer = second(&gen(example.input).unwrap()).to_string();
+        assert_eq!(Some(answer.as_str()), example.second);
+    }
+}
+"#;
+
+/// Example of a day, to be filled in from the puzzle text. `{day}` stands for its number.
+const EXAMPLE_TEMPLATE: &str = r#"const DAY{day}: &[Example] = &[Example {
+    input: "",
+    first: None,
+    second: None,
+}];
+"#;
+
+/// Starting point of a year, `{year}` standing for its number and `{day}` for its first day.
+const YEAR_TEMPLATE: &str = r#"pub mod day{day};
+pub mod examples;
+
+use crate::generate::Generator;
+use crate::solution::{DynSolution, Year};
+
+/// Every solved day of {year}, in order.
+pub static SOLUTIONS: &[&dyn DynSolution] = &[&day{day}::Day{day}];
+
+pub static YEAR: Year = Year {
+    year: {year},
+    solutions: SOLUTIONS,
+    examples: examples::examples,
+    generator,
+};
+
+/// See [`crate::generate`].
+fn generator(_day: u8) -> Option<Generator> {
+    None
+}
+"#;
+
+/// Starting point of the examples table of a year, which gets the entry of its first day.
+const EXAMPLES_TEMPLATE: &str = r#"use crate::examples::Example;
+
+/// See [`crate::examples`].
+pub fn examples(day: u8) -> &'static [Example] {
+    match day {
+        _ => &[],
+    }
+}
+"#;
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("cannot read {}", path.display()))
+}
+
+/// Declares `module` among the `pub mod` lines of `content`, keeping them sorted like rustfmt
+/// does.
+fn declare_module(content: &str, module: &str) -> Option<String> {
+    let mods = Regex::new(r"^pub mod (\w+);$").unwrap();
+    let mut lines: Vec<&str> = content.lines().collect();
+    let declared: Vec<usize> = (0..lines.len())
+        .filter(|&i| mods.is_match(lines[i]))
+        .collect();
+    let position = declared
+        .iter()
+        .copied()
+        .find(|&i| &mods.captures(lines[i]).unwrap()[1] > module)
+        .unwrap_or(*declared.last()? + 1);
+    let line = format!("pub mod {};", module);
+    lines.insert(position, &line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// The number in an item like `&day12::Day12` or `&y2021::YEAR`, to sort them by.
+fn number_in(item: &str) -> u32 {
+    let digits: String = item
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().unwrap_or(0)
+}
+
+/// Adds `item` to the slice assigned to the static `name`, keeping it sorted by number and laid
+/// out like rustfmt does: on one line while it is short, one item per line after that.
+fn add_to_list(content: &str, name: &str, item: &str) -> Option<String> {
+    let pattern = format!(
+        r"(?s)(pub static {}: [^=]+= &\[)(.*?)\];",
+        regex::escape(name)
+    );
+    let captures = Regex::new(&pattern).unwrap().captures(content)?;
+    let (whole, start) = (captures.get(0)?, captures.get(1)?.as_str());
+    let mut items: Vec<&str> = captures[2]
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect();
+    items.push(item);
+    items.sort_by_key(|item| number_in(item));
+    let one_line = items.join(", ");
+    let list = if one_line.len() <= 60 && start.len() + one_line.len() + 2 <= 100 {
+        format!("{}{}];", start, one_line)
+    } else {
+        let lines: String = items
+            .iter()
+            .map(|item| format!("    {},\n", item))
+            .collect();
+        format!("{}\n{}];", start, lines)
+    };
+    Some(format!(
+        "{}{}{}",
+        &content[..whole.start()],
+        list,
+        &content[whole.end()..]
+    ))
+}
+
+/// Adds an empty example for `day` to the examples table `content`, both its `DAYN` constant and
+/// its arm in the `examples` match, in order of days. Days having examples already are left as
+/// they are.
+fn add_example(content: &str, day: u8) -> Option<String> {
+    let constant = Regex::new(r"^const DAY(\d+): &\[Example\]").unwrap();
+    let arm = Regex::new(r"^ +(\d+) => DAY\d+,$").unwrap();
+    let day_in = |re: &Regex, line: &str| re.captures(line).and_then(|c| c[1].parse::<u8>().ok());
+    let mut lines: Vec<&str> = content.lines().collect();
+    let constants: Vec<Option<u8>> = lines.iter().map(|line| day_in(&constant, line)).collect();
+    if constants.contains(&Some(day)) {
+        return Some(content.to_string());
+    }
+
+    let entry = EXAMPLE_TEMPLATE.replace("{day}", &day.to_string());
+    match constants.iter().position(|&other| other > Some(day)) {
+        // The entry ends with a newline, which separates it from the next one
+        Some(i) => lines.insert(i, &entry),
+        None => lines.extend(["", entry.trim_end()]),
+    }
+    let wildcard = lines.iter().position(|line| line.trim() == "_ => &[],")?;
+    let position = lines[..wildcard]
+        .iter()
+        .position(|line| day_in(&arm, line) > Some(day))
+        .unwrap_or(wildcard);
+    let indent = &lines[wildcard][..lines[wildcard].find('_')?];
+    let line = format!("{}{} => DAY{},", indent, day, day);
+    lines.insert(position, &line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Creates `src/yYEAR/dayN.rs` under `root` from the template and registers it and its example in
+/// its year module, creating and registering that one too for a new year. Returns the files
+/// written.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("day {} is not between 1 and 25", day);
+    }
+    let src = root.join("src");
+    let dir = src.join(format!("y{}", year));
+    let module = dir.join(format!("day{}.rs", day));
+    if module.exists() {
+        bail!("{} already exists, not overwriting it", module.display());
+    }
+
+    // Everything is worked out before writing anything
+    let mut files = Vec::new();
+    let year_module = dir.join("mod.rs");
+    let examples = dir.join("examples.rs");
+    if year_module.exists() {
+        let content = read(&year_module)?;
+        let name = format!("day{}", day);
+        if content
+            .lines()
+            .any(|line| line == format!("pub mod {};", name))
+        {
+            bail!("{} is already declared in {}", name, year_module.display());
+        }
+        let content = declare_module(&content, &name)
+            .and_then(|content| {
+                add_to_list(&content, "SOLUTIONS", &format!("&{0}::Day{1}", name, day))
+            })
+            .with_context(|| format!("cannot find the days in {}", year_module.display()))?;
+        files.push((year_module, content));
+        let content = add_example(&read(&examples)?, day)
+            .with_context(|| format!("cannot find the examples in {}", examples.display()))?;
+        files.push((examples, content));
+    } else {
+        let content = YEAR_TEMPLATE
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string());
+        files.push((year_module, content));
+        files.push((examples, add_example(EXAMPLES_TEMPLATE, day).unwrap()));
+        let lib = src.join("lib.rs");
+        let content = declare_module(&read(&lib)?, &format!("y{}", year))
+            .with_context(|| format!("cannot find the modules in {}", lib.display()))?;
+        files.push((lib, content));
+        let registry = src.join("solution.rs");
+        let content = add_to_list(&read(&registry)?, "YEARS", &format!("&y{}::YEAR", year))
+            .with_context(|| format!("cannot find the years in {}", registry.display()))?;
+        files.push((registry, content));
+        fs::create_dir_all(&dir).with_context(|| format!("cannot create {}", dir.display()))?;
+    }
+
+    // Fails if the module appeared in the meantime, the other files are left untouched then
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&module)
+        .with_context(|| format!("cannot create {}", module.display()))?;
+    let content = TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string());
+    file.write_all(content.as_bytes())?;
+    for (path, content) in &files {
+        fs::write(path, content).with_context(|| format!("cannot write {}", path.display()))?;
+    }
+    let written = files.into_iter().map(|(path, _)| path);
+    Ok(std::iter::once(module).chain(written).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn modules() {
+        let lib = "pub mod answer;\npub mod day1;\npub mod day18;\npub mod day2;\nmod matrix;\n";
+        let lib = declare_module(lib, "day19").unwrap();
+        assert_eq!(
+            lib,
+            "pub mod answer;\npub mod day1;\npub mod day18;\npub mod day19;\npub mod day2;\nmod matrix;\n"
+        );
+        let lib = declare_module(&lib, "y2022").unwrap();
+        assert!(lib.ends_with("pub mod day2;\npub mod y2022;\nmod matrix;\n"));
+    }
+
+    #[test]
+    fn lists() {
+        let years = "/// Every year\npub static YEARS: &[&Year] = &[&y2021::YEAR];\n";
+        let years = add_to_list(years, "YEARS", "&y2015::YEAR").unwrap();
+        assert!(years.contains("= &[&y2015::YEAR, &y2021::YEAR];\n"));
+
+        let registry = add_to_list(
+            include_str!("../y2021/mod.rs"),
+            "SOLUTIONS",
+            "&day19::Day19",
+        );
+        assert!(registry
+            .unwrap()
+            .contains("    &day18::Day18,\n    &day19::Day19,\n    &day20::Day20,\n"));
+        let registry = add_to_list(include_str!("../solution.rs"), "YEARS", "&y2022::YEAR");
+        assert!(registry
+            .unwrap()
+            .contains("YEARS: &[&Year] = &[&y2021::YEAR, &y2022::YEAR];"));
+
+        let mut list = "pub static SOLUTIONS: &[&dyn DynSolution] = &[&day1::Day1];".to_string();
+        for day in 2..=5 {
+            list = add_to_list(&list, "SOLUTIONS", &format!("&day{0}::Day{0}", day)).unwrap();
+        }
+        assert!(list.ends_with("= &[\n    &day1::Day1,\n    &day2::Day2,\n    &day3::Day3,\n    &day4::Day4,\n    &day5::Day5,\n];"));
+    }
+
+    #[test]
+    fn examples() {
+        let empty = |day: u8| EXAMPLE_TEMPLATE.replace("{day}", &day.to_string());
+        let table = add_example(include_str!("../y2021/examples.rs"), 19).unwrap();
+        let arms = "        18 => DAY18,\n        19 => DAY19,\n        20 => DAY20,\n";
+        assert!(table.contains(arms));
+        assert!(table.contains(&format!("}}];\n\n{}\nconst DAY20: &[Example]", empty(19))));
+        assert_eq!(add_example(&table, 25).unwrap(), table);
+
+        let table = add_example(EXAMPLES_TEMPLATE, 3).unwrap();
+        let table = add_example(&table, 1).unwrap();
+        assert!(table.contains("        1 => DAY1,\n        3 => DAY3,\n        _ => &[],\n"));
+        assert!(table.ends_with(&format!("}}\n\n{}\n{}", empty(1), empty(3))));
+    }
+
+    #[test]
+    fn scaffolding() {
+        let root = std::env::temp_dir().join(format!("new-day-{}", std::process::id()));
+        let src = root.join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("lib.rs"), "pub mod answer;\npub mod y2021;\n").unwrap();
+        fs::write(
+            src.join("solution.rs"),
+            "pub static YEARS: &[&Year] = &[&y2021::YEAR];\n",
+        )
+        .unwrap();
+
+        let first = new_day(&root, 2022, 3).map(|written| written.len());
+        let second = new_day(&root, 2022, 1).map(|written| written.len());
+        let read = |path: &str| fs::read_to_string(src.join(path)).unwrap();
+        let (lib, registry, year, examples, day) = (
+            read("lib.rs"),
+            read("solution.rs"),
+            read("y2022/mod.rs"),
+            read("y2022/examples.rs"),
+            read("y2022/day1.rs"),
+        );
+        let again = new_day(&root, 2022, 3).unwrap_err().to_string();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!((first.unwrap(), second.unwrap()), (5, 3));
+        assert_eq!(lib, "pub mod answer;\npub mod y2021;\npub mod y2022;\n");
+        assert!(registry.contains("&[&y2021::YEAR, &y2022::YEAR];"));
+        assert!(year.starts_with("pub mod day1;\npub mod day3;\npub mod examples;\n"));
+        assert!(year.contains("= &[&day1::Day1, &day3::Day3];"));
+        assert!(examples.contains("        1 => DAY1,\n        3 => DAY3,\n"));
+        assert!(day.contains("impl Solution for Day1 {\n    const DAY: u8 = 1;"));
+        assert!(day.contains("        examples(2022, 1)[0]\n"));
+        assert!(again.contains("already exists"), "{}", again);
+    }
+}
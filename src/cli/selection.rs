@@ -0,0 +1,148 @@
+use advent_2021::Part;
+use anyhow::{bail, Context, Error, Result};
+use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+const DAYS: RangeInclusive<u8> = 1..=25;
+
+/// Days written as a comma separated list of days and ranges, like `3-7,12,20-25`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Days(pub BTreeSet<u8>);
+
+pub fn parse_day(s: &str) -> Result<u8> {
+    let day = s
+        .trim()
+        .parse()
+        .with_context(|| format!("invalid day \"{}\"", s))?;
+    if !DAYS.contains(&day) {
+        bail!("day {} is not between 1 and 25", day);
+    }
+    Ok(day)
+}
+
+fn parse_days(s: &str) -> Result<RangeInclusive<u8>> {
+    match s.split_once('-') {
+        Some((from, to)) => {
+            let (from, to) = (parse_day(from)?, parse_day(to)?);
+            if from > to {
+                bail!("empty day range \"{}\"", s);
+            }
+            Ok(from..=to)
+        }
+        None => {
+            let day = parse_day(s)?;
+            Ok(day..=day)
+        }
+    }
+}
+
+impl FromStr for Days {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = BTreeSet::new();
+        for item in s.split(',') {
+            days.extend(parse_days(item)?);
+        }
+        Ok(Days(days))
+    }
+}
+
+/// A `day:part` pair, the day being allowed to be a range, like `15:2` or `20-25:1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Only {
+    days: RangeInclusive<u8>,
+    part: Part,
+}
+
+impl FromStr for Only {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (days, part) = s
+            .split_once(':')
+            .with_context(|| format!("expected day:part, got \"{}\"", s))?;
+        Ok(Only {
+            days: parse_days(days)?,
+            part: part.parse()?,
+        })
+    }
+}
+
+/// The set of `(day, part)` pairs a run should go through.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection(BTreeSet<(u8, Part)>);
+
+impl Selection {
+    pub fn new(days: Option<&Days>, part: Option<Part>, only: &[Only]) -> Self {
+        if !only.is_empty() {
+            return Selection(
+                only.iter()
+                    .flat_map(|o| o.days.clone().map(move |d| (d, o.part)))
+                    .collect(),
+            );
+        }
+        let days = days.map_or_else(|| DAYS.collect(), |d| d.0.clone());
+        let parts = part.map_or_else(|| Part::BOTH.to_vec(), |p| vec![p]);
+        Selection(
+            days.into_iter()
+                .flat_map(|d| parts.iter().map(move |&p| (d, p)))
+                .collect(),
+        )
+    }
+
+    pub fn days(&self) -> BTreeSet<u8> {
+        self.0.iter().map(|(d, _)| *d).collect()
+    }
+
+    pub fn parts(&self, day: u8) -> Vec<Part> {
+        self.0
+            .iter()
+            .filter(|(d, _)| *d == day)
+            .map(|(_, p)| *p)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn days() {
+        let days: Days = "3-7,12,20-25".parse().unwrap();
+        assert_eq!(
+            days.0.into_iter().collect::<Vec<_>>(),
+            [3, 4, 5, 6, 7, 12, 20, 21, 22, 23, 24, 25]
+        );
+        assert!("0".parse::<Days>().is_err());
+        assert!("7-3".parse::<Days>().is_err());
+        assert!("3,x".parse::<Days>().is_err());
+    }
+
+    #[test]
+    fn day_and_part() {
+        let days: Days = "3,5".parse().unwrap();
+        let selection = Selection::new(Some(&days), Some(Part::Two), &[]);
+        assert_eq!(selection.days().into_iter().collect::<Vec<_>>(), [3, 5]);
+        assert_eq!(selection.parts(3), [Part::Two]);
+        assert!(selection.parts(4).is_empty());
+    }
+
+    #[test]
+    fn only() {
+        let only = ["15:2".parse().unwrap(), "1-2:1".parse().unwrap()];
+        let selection = Selection::new(None, None, &only);
+        assert_eq!(selection.days().into_iter().collect::<Vec<_>>(), [1, 2, 15]);
+        assert_eq!(selection.parts(15), [Part::Two]);
+        assert_eq!(selection.parts(2), [Part::One]);
+        assert!("15".parse::<Only>().is_err());
+        assert!("15:3".parse::<Only>().is_err());
+    }
+
+    #[test]
+    fn everything_by_default() {
+        let selection = Selection::new(None, None, &[]);
+        assert_eq!(selection.days().len(), 25);
+        assert_eq!(selection.parts(25), Part::BOTH);
+    }
+}
@@ -0,0 +1,279 @@
+use super::input::Source;
+use super::output::Status;
+use super::selection::{self, Days, Selection};
+use advent_2021::Part;
+use anyhow::{bail, Context, Result};
+use serde_json::json;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::Duration;
+
+/// Puzzle inputs are a few dozen KiB, anything much larger is a mistake.
+const MAX_BODY: usize = 10 << 20;
+/// Longest request line or header line accepted.
+const MAX_LINE: usize = 8 << 10;
+const MAX_HEADERS: usize = 100;
+/// How long a client may stay silent while sending its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    /// JSON text
+    pub body: String,
+}
+
+impl Response {
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Response {
+            status,
+            body: json!({ "error": message.into() }).to_string(),
+        }
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        414 => "URI Too Long",
+        422 => "Unprocessable Entity",
+        431 => "Request Header Fields Too Large",
+        504 => "Gateway Timeout",
+        _ => "Internal Server Error",
+    }
+}
+
+/// Reads a line of the head of a request into `line`, answering `too_long` to lines over
+/// [`MAX_LINE`].
+fn read_line(reader: &mut impl BufRead, line: &mut String, too_long: u16) -> Result<(), Response> {
+    line.clear();
+    reader
+        .take(MAX_LINE as u64 + 1)
+        .read_line(line)
+        .map_err(|_| Response::error(400, "cannot read the request"))?;
+    if line.len() > MAX_LINE {
+        let message = format!("request and header lines are limited to {} bytes", MAX_LINE);
+        return Err(Response::error(too_long, message));
+    }
+    Ok(())
+}
+
+/// Reads a request from `reader`. Clients waiting for `100 Continue` before sending the body, like
+/// curl does for large ones, are told to go on through `writer`.
+pub fn read_request(mut reader: impl BufRead, mut writer: impl Write) -> Result<Request, Response> {
+    let bad = |message: &str| Response::error(400, message);
+    let mut line = String::new();
+    read_line(&mut reader, &mut line, 414)?;
+    let mut words = line.split_whitespace();
+    let (method, path) = match (words.next(), words.next()) {
+        (Some(method), Some(path)) => (method.to_string(), path.to_string()),
+        _ => return Err(bad("invalid request line")),
+    };
+
+    let (mut length, mut expect_continue) = (None, false);
+    for count in 0.. {
+        read_line(&mut reader, &mut line, 431)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if count == MAX_HEADERS {
+            let message = format!("requests are limited to {} headers", MAX_HEADERS);
+            return Err(Response::error(431, message));
+        }
+        let (name, value) = header
+            .split_once(':')
+            .ok_or_else(|| bad("invalid header"))?;
+        let value = value.trim();
+        match name.trim().to_ascii_lowercase().as_str() {
+            "content-length" => {
+                length = Some(value.parse().map_err(|_| bad("invalid Content-Length"))?)
+            }
+            "transfer-encoding" => {
+                return Err(Response::error(
+                    411,
+                    "only Content-Length bodies are supported",
+                ))
+            }
+            "expect" => expect_continue = value.eq_ignore_ascii_case("100-continue"),
+            _ => {}
+        }
+    }
+
+    let length = length.unwrap_or(0);
+    if length > MAX_BODY {
+        return Err(Response::error(
+            413,
+            format!("inputs are limited to {} bytes", MAX_BODY),
+        ));
+    }
+    if expect_continue && length > 0 {
+        writer
+            .write_all(b"HTTP/1.1 100 Continue\r\n\r\n")
+            .map_err(|_| bad("cannot accept the body"))?;
+    }
+    let mut body = vec![0; length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|_| bad("body shorter than its Content-Length"))?;
+    let body = String::from_utf8(body).map_err(|_| bad("the input is not UTF-8"))?;
+    Ok(Request { method, path, body })
+}
+
+/// Answers `POST /solve/{day}/{part}` with the JSON record of that part, the same as
+/// `--format json` gives for it.
+pub fn respond(year: u16, request: &Request, timeout: Option<Duration>) -> Response {
+    let path = request.path.split('?').next().unwrap_or_default();
+    let (day, part) = match path.strip_prefix("/solve/").map(|p| p.split_once('/')) {
+        Some(Some((day, part))) => (day, part),
+        _ => return Response::error(404, format!("no route for {}", path)),
+    };
+    if request.method != "POST" {
+        return Response::error(405, format!("{} needs POST", path));
+    }
+    let (day, part): (u8, Part) = match (selection::parse_day(day), part.parse()) {
+        (Ok(day), Ok(part)) => (day, part),
+        (Err(e), _) | (_, Err(e)) => return Response::error(400, e.to_string()),
+    };
+
+    let days = Days([day].into());
+    let selection = Selection::new(Some(&days), Some(part), &[]);
+    let source = Source::Body(request.body.clone());
+    let solution = advent_2021::solution(year, day);
+    let record = super::run::run(solution, day, &selection, &source, timeout).remove(0);
+    let status = match record.status {
+        Status::Ok => 200,
+        Status::NotImplemented | Status::Skipped => 404,
+        Status::Error => 422,
+        Status::Timeout => 504,
+    };
+    Response {
+        status,
+        body: serde_json::to_string(&record).unwrap(),
+    }
+}
+
+fn handle(year: u16, stream: TcpStream, timeout: Option<Duration>) -> Result<()> {
+    stream
+        .set_read_timeout(Some(READ_TIMEOUT))
+        .context("cannot set the read timeout")?;
+    let request = read_request(BufReader::new(&stream), &stream);
+    let response = match &request {
+        // Without a timeout, solvers run on this thread
+        Ok(request) => panic::catch_unwind(AssertUnwindSafe(|| respond(year, request, timeout)))
+            .unwrap_or_else(|_| Response::error(500, "the solver panicked")),
+        Err(response) => response.clone(),
+    };
+    if let Ok(request) = &request {
+        println!("{} {} {}", request.method, request.path, response.status);
+    }
+    let body = &response.body;
+    write!(
+        &stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        body.len(),
+        body
+    )
+    .context("cannot send the response")
+}
+
+/// Answers requests on `port` of the local host until killed, each connection on its own
+/// thread.
+pub fn serve(year: u16, port: u16, timeout: Option<Duration>) -> Result<()> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .with_context(|| format!("cannot listen on port {}", port))?;
+    println!("listening on http://{}", listener.local_addr()?);
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("warning: cannot accept a connection: {}", e);
+                continue;
+            }
+        };
+        thread::spawn(move || {
+            if let Err(e) = handle(year, stream, timeout) {
+                eprintln!("warning: {:#}", e);
+            }
+        });
+    }
+    bail!("stopped listening on port {}", port)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn request(method: &str, path: &str, body: &str) -> Request {
+        Request {
+            method: method.to_string(),
+            path: path.to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn reading() {
+        let raw = "POST /solve/7/1 HTTP/1.1\r\nHost: localhost\r\ncontent-length: 5\r\n\
+                   Expect: 100-continue\r\n\r\n16,1,2";
+        let mut sent = Vec::new();
+        let read = read_request(raw.as_bytes(), &mut sent);
+        assert_eq!(read, Ok(request("POST", "/solve/7/1", "16,1,")));
+        assert_eq!(sent, b"HTTP/1.1 100 Continue\r\n\r\n");
+
+        let raw = "POST /solve/7/1 HTTP/1.1\r\nContent-Length: 9\r\n\r\n16,1";
+        let response = read_request(raw.as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(response.status, 400);
+        let raw = "POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n";
+        let response = read_request(raw.as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(response.status, 411);
+
+        let long = "x".repeat(MAX_LINE);
+        let raw = format!("POST /solve/7/1?{} HTTP/1.1\r\n\r\n", long);
+        let response = read_request(raw.as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(response.status, 414);
+        let raw = format!("POST / HTTP/1.1\r\nX-Long: {}\r\n\r\n", long);
+        let response = read_request(raw.as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(response.status, 431);
+        let raw = format!("POST / HTTP/1.1\r\n{}\r\n", "X-Many: 1\r\n".repeat(101));
+        let response = read_request(raw.as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(response.status, 431);
+        let raw = format!("POST / HTTP/1.1\r\n{}\r\n", "X-Many: 1\r\n".repeat(100));
+        assert!(read_request(raw.as_bytes(), Vec::new()).is_ok());
+    }
+
+    #[test]
+    fn responses() {
+        let input = "16,1,2,0,4,2,7,1,2,14";
+        let response = respond(2021, &request("POST", "/solve/7/2", input), None);
+        assert_eq!(response.status, 200);
+        let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(body["answer"], "168");
+        assert!(body["solve_time_ns"].is_u64());
+
+        let status = |method, path, body| respond(2021, &request(method, path, body), None).status;
+        assert_eq!(status("POST", "/solve/7/1", "16,x"), 422);
+        assert_eq!(status("POST", "/solve/19/1", input), 404);
+        assert_eq!(status("POST", "/solve/26/1", input), 400);
+        assert_eq!(status("POST", "/solve/7/3", input), 400);
+        assert_eq!(status("GET", "/solve/7/1", input), 405);
+        assert_eq!(status("POST", "/answers", input), 404);
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt;
+use std::time::Duration;
+
+/// Summary of repeated timings of the same step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// Returns `None` when there are no samples.
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}  std dev {:>10.2?}",
+            self.min, self.median, self.mean, self.std_dev
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn odd() {
+        let stats = Stats::new(&[ms(4), ms(2), ms(6)]).unwrap();
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(4));
+        assert_eq!(stats.mean, ms(4));
+        assert_eq!(stats.std_dev.as_micros(), 1632);
+    }
+
+    #[test]
+    fn even() {
+        let stats = Stats::new(&[ms(1), ms(3), ms(5), ms(7)]).unwrap();
+        assert_eq!(stats.median, ms(4));
+        assert!(Stats::new(&[]).is_none());
+    }
+}
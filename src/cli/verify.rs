@@ -0,0 +1,175 @@
+use advent_2021::Part;
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Default, Deserialize)]
+struct DayAnswers {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+}
+
+/// Expected answers, read from a TOML file with one table per day:
+///
+/// ```toml
+/// [day1]
+/// part1 = 1602
+/// part2 = "1633"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("cannot read answers file {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("invalid answers file {}", path.display()))
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// The answers the puzzle texts give for the `n`th example of every day of `year`.
+    pub fn examples(year: u16, n: usize) -> Self {
+        let value = |answer: Option<&str>| answer.map(|a| toml::Value::String(a.to_string()));
+        let days = (1..=25)
+            .filter_map(|day| {
+                let example = advent_2021::examples(year, day).get(n.checked_sub(1)?)?;
+                let answers = DayAnswers {
+                    part1: value(example.first),
+                    part2: value(example.second),
+                };
+                Some((format!("day{}", day), answers))
+            })
+            .collect();
+        Answers(days)
+    }
+
+    pub fn expected(&self, day: u8, part: Part) -> Option<String> {
+        let answers = self.0.get(&format!("day{}", day))?;
+        let value = match part {
+            Part::One => answers.part1.as_ref(),
+            Part::Two => answers.part2.as_ref(),
+        }?;
+        Some(match value {
+            toml::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+/// Compares `actual` to the expected answer and prints the outcome, with a line diff on failure.
+pub fn check(answers: &Answers, day: u8, part: Part, actual: &str) -> Verdict {
+    let expected = match answers.expected(day, part) {
+        None => {
+            println!("part {}: {} ({})", part, "no answer".yellow(), actual);
+            return Verdict::Unknown;
+        }
+        Some(expected) => expected,
+    };
+    if expected.trim_end() == actual.trim_end() {
+        println!("part {}: {} ({})", part, "PASS".green(), actual);
+        return Verdict::Pass;
+    }
+    println!("part {}: {}", part, "FAIL".red());
+    print!("{}", diff(&expected, actual));
+    Verdict::Fail
+}
+
+/// Prints that the part has no solution, which fails the check if an answer is expected.
+pub fn not_implemented(answers: &Answers, day: u8, part: Part) -> Verdict {
+    if answers.expected(day, part).is_none() {
+        println!("part {}: {}", part, "not implemented".yellow());
+        return Verdict::Unknown;
+    }
+    println!("part {}: {}", part, "NOT IMPLEMENTED".red());
+    Verdict::Fail
+}
+
+/// Prints that the solver gave no answer to compare, which fails the check.
+pub fn error(part: Part, message: &str) -> Verdict {
+    println!("part {}: {}\n{}", part, "ERROR".red(), message);
+    Verdict::Fail
+}
+
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<_> = expected.trim_end().lines().collect();
+    let actual: Vec<_> = actual.trim_end().lines().collect();
+    let mut out = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => out += &format!("    {}\n", e),
+            (e, a) => {
+                if let Some(e) = e {
+                    out += &format!("  {} {}\n", "-".red(), e.red());
+                }
+                if let Some(a) = a {
+                    out += &format!("  {} {}\n", "+".green(), a.green());
+                }
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use regex::Regex;
+
+    fn answers() -> Answers {
+        Answers::parse(
+            r#"
+[day1]
+part1 = 7
+part2 = "5"
+
+[day13]
+part1 = 17
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn expected() {
+        let answers = answers();
+        assert_eq!(answers.expected(1, Part::One).as_deref(), Some("7"));
+        assert_eq!(answers.expected(1, Part::Two).as_deref(), Some("5"));
+        assert_eq!(answers.expected(13, Part::Two), None);
+        assert_eq!(answers.expected(2, Part::One), None);
+    }
+
+    #[test]
+    fn verdicts() {
+        let answers = answers();
+        assert_eq!(check(&answers, 1, Part::One, "7"), Verdict::Pass);
+        assert_eq!(check(&answers, 1, Part::Two, "6"), Verdict::Fail);
+        assert_eq!(check(&answers, 13, Part::Two, "0"), Verdict::Unknown);
+        assert_eq!(not_implemented(&answers, 1, Part::Two), Verdict::Fail);
+        assert_eq!(not_implemented(&answers, 13, Part::Two), Verdict::Unknown);
+    }
+
+    #[test]
+    fn line_diff() {
+        // Colors are on or off depending on the terminal, so they are left out of the comparison
+        let colors = Regex::new("\x1b\\[[0-9;]*m").unwrap();
+        let diff = diff("a\nb", "a\nc\nd");
+        assert_eq!(
+            colors.replace_all(&diff, ""),
+            "    a\n  - b\n  + c\n  + d\n"
+        );
+    }
+}
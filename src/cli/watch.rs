@@ -0,0 +1,229 @@
+use super::input::Source;
+use super::output::{Record, Status};
+use super::selection::Selection;
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Carries the outcome of the last run over to the restarted process after a rebuild.
+const PREVIOUS_VAR: &str = "ADVENT_WATCH_PREVIOUS";
+
+/// What a part gave in one run, the answer being replaced by the status when there is none.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartRun {
+    pub part: u8,
+    pub result: String,
+    pub time_ns: Option<u64>,
+}
+
+impl PartRun {
+    fn new(r: &Record) -> Self {
+        let result = match (r.status, &r.answer) {
+            (Status::Ok, Some(answer)) => answer.to_string().replace('\n', " / "),
+            (Status::Ok, None) => String::new(),
+            (Status::NotImplemented, _) => "not implemented".to_string(),
+            (Status::Skipped, _) => "skipped".to_string(),
+            (Status::Timeout, _) => "TIMEOUT".to_string(),
+            (Status::Error, _) => format!("error: {}", r.error.as_deref().unwrap_or_default()),
+        };
+        let time_ns = match (r.parse_time_ns, r.solve_time_ns) {
+            (Some(parse), Some(solve)) => Some(parse.saturating_add(solve)),
+            _ => None,
+        };
+        PartRun {
+            part: r.part,
+            result,
+            time_ns,
+        }
+    }
+}
+
+fn format_time(ns: Option<u64>) -> String {
+    ns.map_or_else(
+        || "-".to_string(),
+        |ns| format!("{:.2?}", Duration::from_nanos(ns)),
+    )
+}
+
+/// One line per part of `current`, telling how it differs from the `previous` run, and whether
+/// its result changed.
+pub fn diff(previous: &[PartRun], current: &[PartRun]) -> Vec<(bool, String)> {
+    current
+        .iter()
+        .map(|now| {
+            let before = previous.iter().find(|p| p.part == now.part);
+            let result = match before {
+                Some(before) if before.result != now.result => {
+                    format!("{} -> {}", before.result, now.result)
+                }
+                _ => now.result.clone(),
+            };
+            let time = match (before.and_then(|b| b.time_ns), now.time_ns) {
+                (Some(before), Some(now)) => format!(
+                    "{} -> {}, {:+.0}%",
+                    format_time(Some(before)),
+                    format_time(Some(now)),
+                    (now as f64 / before.max(1) as f64 - 1.0) * 100.0
+                ),
+                (_, now) => format_time(now),
+            };
+            let changed = before.is_some_and(|b| b.result != now.result);
+            (changed, format!("part {}: {} ({})", now.part, result, time))
+        })
+        .collect()
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Rebuilds this binary with the profile and features it was built with.
+fn rebuild() -> Result<bool> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let mut command = Command::new(cargo);
+    command
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(manifest)
+        .args(["--bin", env!("CARGO_BIN_NAME")]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    if cfg!(feature = "alloc-stats") {
+        command.args(["--features", "alloc-stats"]);
+    }
+    let status = command.status().context("cannot run cargo")?;
+    Ok(status.success())
+}
+
+/// Replaces this process by the freshly built `exe`, with the same arguments.
+fn restart(exe: &Path, previous: &[PartRun]) -> Result<Infallible> {
+    let mut command = Command::new(exe);
+    command
+        .args(env::args_os().skip(1))
+        .env(PREVIOUS_VAR, serde_json::to_string(previous)?);
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        Err(command.exec()).context("cannot restart")
+    }
+    #[cfg(not(unix))]
+    {
+        let status = command.status().context("cannot restart")?;
+        std::process::exit(status.code().unwrap_or(1));
+    }
+}
+
+/// Runs the selected parts of a single day every time its input changes, and with `rebuild`
+/// every time its `src/yYEAR/dayN.rs` changes too, printing how the answers and timings moved.
+pub fn watch(
+    year: u16,
+    selection: &Selection,
+    source: &Source,
+    timeout: Option<Duration>,
+    interval: Duration,
+    rebuild: bool,
+) -> Result<()> {
+    let days = selection.days();
+    let day = match days.iter().next() {
+        Some(&day) if days.len() == 1 => day,
+        _ => bail!(
+            "watch needs exactly one day to be selected, got {}",
+            days.len()
+        ),
+    };
+    let input = source.path(day).context("can only watch an input file")?;
+    let code: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "src",
+        &format!("y{}", year),
+        &format!("day{}.rs", day),
+    ]
+    .iter()
+    .collect();
+    // Looked up before rebuilding, the running binary is then seen as deleted
+    let exe = env::current_exe().context("cannot find the running binary")?;
+
+    let mut previous: Vec<PartRun> = match env::var(PREVIOUS_VAR) {
+        Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+    let mut reason = format!("watching {}", input.display());
+    if rebuild {
+        reason += &format!(" and {}", code.display());
+    }
+    loop {
+        let (input_time, mut code_time) = (modified(&input), modified(&code));
+        println!("\n day: {} ({})", day, reason);
+        let solution = advent_2021::solution(year, day);
+        let records = super::run::run(solution, day, selection, source, timeout);
+        let current: Vec<PartRun> = records.iter().map(PartRun::new).collect();
+        for (changed, line) in diff(&previous, &current) {
+            if changed {
+                println!("{}", line.yellow());
+            } else {
+                println!("{}", line);
+            }
+        }
+        previous = current;
+
+        loop {
+            thread::sleep(interval);
+            if rebuild && modified(&code) != code_time {
+                code_time = modified(&code);
+                println!("\n{} changed, rebuilding", code.display());
+                if self::rebuild()? {
+                    match restart(&exe, &previous)? {}
+                }
+                // Keep watching the broken source, it is most likely being fixed
+                eprintln!("{}", "build failed".red());
+                continue;
+            }
+            if modified(&input) != input_time {
+                reason = format!("{} changed", input.display());
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn part(part: u8, result: &str, time_ns: Option<u64>) -> PartRun {
+        PartRun {
+            part,
+            result: result.to_string(),
+            time_ns,
+        }
+    }
+
+    #[test]
+    fn diffs() {
+        let first = [part(1, "37", Some(2000)), part(2, "168", Some(1000))];
+        assert_eq!(
+            diff(&[], &first),
+            [
+                (false, "part 1: 37 (2.00µs)".to_string()),
+                (false, "part 2: 168 (1.00µs)".to_string())
+            ]
+        );
+        let second = [part(1, "37", Some(1000)), part(2, "TIMEOUT", None)];
+        assert_eq!(
+            diff(&first, &second),
+            [
+                (false, "part 1: 37 (2.00µs -> 1.00µs, -50%)".to_string()),
+                (true, "part 2: 168 -> TIMEOUT (-)".to_string())
+            ]
+        );
+    }
+}
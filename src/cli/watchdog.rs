@@ -0,0 +1,82 @@
+use advent_2021::cancel::{self, panic_message, Token};
+use anyhow::{bail, Context, Error};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+pub enum Outcome<T> {
+    Done(T),
+    TimedOut,
+    Panicked(String),
+}
+
+/// Runs `f` on its own thread and waits for it at most `timeout`. Past that, its cancellation
+/// token is set and `f` is abandoned: it stops at its next [`cancel::checkpoint`], if any.
+pub fn watch<T, F>(timeout: Duration, f: F) -> Outcome<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let token = Token::new();
+    let solver_token = token.clone();
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| cancel::with_token(solver_token, f)));
+        // The watchdog may have given up already
+        let _ = sender.send(result);
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(Ok(value))) => Outcome::Done(value),
+        Ok(Ok(Err(cancel::Cancelled))) | Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            Outcome::TimedOut
+        }
+        Ok(Err(payload)) => Outcome::Panicked(panic_message(payload)),
+        Err(RecvTimeoutError::Disconnected) => Outcome::Panicked("solver thread died".to_string()),
+    }
+}
+
+/// Parses durations like `2`, `1.5s` or `500ms`, plain numbers being seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, Error> {
+    let s = s.trim();
+    let (number, scale) = if let Some(ms) = s.strip_suffix("ms") {
+        (ms, 1e-3)
+    } else {
+        (s.strip_suffix('s').unwrap_or(s), 1.0)
+    };
+    let number: f64 = number
+        .trim()
+        .parse()
+        .with_context(|| format!("invalid duration \"{}\"", s))?;
+    if !number.is_finite() || number <= 0.0 {
+        bail!("invalid duration \"{}\", expected a positive number", s);
+    }
+    Ok(Duration::from_secs_f64(number * scale))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn outcomes() {
+        let ms = Duration::from_millis;
+        assert!(matches!(watch(ms(1000), || 7), Outcome::Done(7)));
+        let spin = || loop {
+            cancel::checkpoint();
+        };
+        assert!(matches!(watch(ms(10), spin), Outcome::TimedOut));
+        let outcome = watch(ms(1000), || -> u32 { panic!("oops") });
+        assert!(matches!(outcome, Outcome::Panicked(m) if m == "oops"));
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("2").unwrap(), Duration::from_secs(2));
+        assert_eq!(parse_duration("1.5s").unwrap(), Duration::from_millis(1500));
+        assert_eq!(parse_duration("250ms").unwrap(), Duration::from_millis(250));
+        assert!(parse_duration("soon").is_err());
+        assert!(parse_duration("-1").is_err());
+    }
+}
@@ -0,0 +1,12 @@
+use std::ops::Deref;
+
+/// Reads bits, most significant first, as a number.
+pub fn as_number<I, K>(iter: I) -> u64
+where
+    I: Iterator<Item = K> + std::iter::DoubleEndedIterator,
+    K: Deref<Target = bool>,
+{
+    iter.rev().enumerate().fold(0, |acc, (i, bit)| {
+        acc + 2_u64.pow(i.try_into().unwrap()) * *bit as u64
+    })
+}
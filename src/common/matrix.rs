@@ -0,0 +1,881 @@
+//! The grids that many puzzles are played on.
+//!
+//! A [`Matrix`] stores its cells row by row and is indexed by `(x, y)` pairs, `x` being the
+//! column and `y` the row, both counted from the top left corner. Neighbours come in two
+//! flavours: the 4 orthogonal ones, named after the moves of a rook, and all 8 surrounding
+//! cells.
+//!
+//! What lies past the edges depends on the [`Topology`] of the matrix: nothing, the other side
+//! of the matrix, or an endless background.
+//!
+//! Everything that visits cells one after the other, like [`Matrix::iter_with_coords`], does it
+//! in the order they are stored in: row by row from the top, each row from left to right. This
+//! order is part of the interface, simulations can rely on it to be deterministic.
+
+use crate::parse::{self, ParseError, ParseResult};
+use std::fmt;
+use std::ops;
+
+/// How a matrix continues past its edges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Topology<T> {
+    /// Nothing lies past the edges
+    Bounded,
+    /// Leaving through an edge comes back through the opposite one
+    Toroidal,
+    /// The matrix is surrounded by endless cells of this value, which have no position
+    Infinite(T),
+}
+
+/// A rectangle of cells, from `(x, y)` at its top left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// A rectangular grid of `T`s, bounded unless given another [`Topology`].
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<T> {
+    /// The cells, row by row
+    pub vec: Vec<T>,
+    width: usize,
+    height: usize,
+    topology: Topology<T>,
+}
+
+/// The rows of the grid in `input`: its lines without the blank ones around them, the
+/// indentation they share, and trailing whitespace, which covers CRLF line ends. The first row
+/// does not count for the indentation, as it often follows the quote of a string literal.
+fn grid_rows(input: &str) -> ParseResult<Vec<&str>> {
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+    let start = lines
+        .iter()
+        .position(|line| !line.is_empty())
+        .ok_or_else(|| ParseError::end(input, "expected a grid"))?;
+    let end = lines.iter().rposition(|line| !line.is_empty()).unwrap() + 1;
+    let lines = &lines[start..end];
+    if let Some(blank) = lines.iter().find(|line| line.is_empty()) {
+        return Err(ParseError::at(input, blank, "blank line inside the grid"));
+    }
+    let indent = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let shared = lines[1..]
+        .iter()
+        .map(|line| indent(line))
+        .min()
+        .unwrap_or_else(|| indent(lines[0]));
+    Ok(lines
+        .iter()
+        .map(|line| &line[indent(line).min(shared)..])
+        .collect())
+}
+
+/// Turns the characters of `row` into cells, reporting the position of the first bad one.
+fn push_cells<T, E: fmt::Display>(
+    cells: &mut Vec<T>,
+    input: &str,
+    row: &str,
+    cell: &mut impl FnMut(char) -> Result<T, E>,
+) -> ParseResult<()> {
+    for (c, at) in parse::chars(row) {
+        cells.push(cell(c).map_err(|e| ParseError::at(input, at, e.to_string()))?);
+    }
+    Ok(())
+}
+
+impl<T> Matrix<T> {
+    /// Parses a grid of characters, one row per line, turning each character into a cell with
+    /// `cell`. Blank lines around the grid, the indentation shared by its rows and trailing
+    /// whitespace are ignored, the first row being allowed not to be indented. The rows must all
+    /// have the same length.
+    ///
+    /// The errors of `cell` are reported at the position of the character.
+    pub fn parse_grid<E: fmt::Display>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> ParseResult<Self> {
+        let rows = grid_rows(input)?;
+        let width = rows[0].chars().count();
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for row in &rows {
+            let length = row.chars().count();
+            if length != width {
+                let message = format!("row has {} cells, the first one has {}", length, width);
+                return Err(ParseError::at(input, row, message));
+            }
+            push_cells(&mut cells, input, row, &mut cell)?;
+        }
+        Ok(Matrix::new(cells, width, rows.len()))
+    }
+
+    /// Like [`parse_grid`](Self::parse_grid), except that rows shorter than the first one are
+    /// padded at the end with `fill`, as if it were in the input.
+    pub fn parse_grid_padded<E: fmt::Display>(
+        input: &str,
+        fill: char,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> ParseResult<Self> {
+        let rows = grid_rows(input)?;
+        let width = rows[0].chars().count();
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for row in &rows {
+            if let Some((extra, _)) = row.char_indices().nth(width) {
+                let message = "row is wider than the first one";
+                return Err(ParseError::at(input, &row[extra..], message));
+            }
+            push_cells(&mut cells, input, row, &mut cell)?;
+            for _ in row.chars().count()..width {
+                let at = &row[row.len()..];
+                cells.push(cell(fill).map_err(|e| ParseError::at(input, at, e.to_string()))?);
+            }
+        }
+        Ok(Matrix::new(cells, width, rows.len()))
+    }
+
+    /// Makes a matrix of `items` laid out row by row, which must be `width * height` long.
+    pub fn new<I>(items: I, width: usize, height: usize) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            vec: items.into_iter().collect(),
+            width,
+            height,
+            topology: Topology::Bounded,
+        }
+    }
+
+    pub fn with_topology(mut self, topology: Topology<T>) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    pub fn topology(&self) -> &Topology<T> {
+        &self.topology
+    }
+
+    /// The position in the matrix of the cell `(x, y)` stands for: itself when inside, the one
+    /// it wraps around to in a toroidal matrix, and `None` past the edges otherwise.
+    pub fn position(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        if width == 0 || height == 0 {
+            return None;
+        }
+        match self.topology {
+            Topology::Toroidal => {
+                Some((x.rem_euclid(width) as usize, y.rem_euclid(height) as usize))
+            }
+            _ if (0..width).contains(&x) && (0..height).contains(&y) => {
+                Some((x as usize, y as usize))
+            }
+            _ => None,
+        }
+    }
+
+    /// The cell at `(x, y)` under the topology of the matrix, `None` past the edges of a bounded
+    /// one.
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        match (self.position(x, y), &self.topology) {
+            (Some(position), _) => Some(&self[position]),
+            (None, Topology::Infinite(background)) => Some(background),
+            (None, _) => None,
+        }
+    }
+
+    /// The cell at `(x, y)` under the topology of the matrix. The background of an infinite
+    /// matrix cannot be changed, so this is `None` past its edges like past those of a bounded
+    /// one.
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        let position = self.position(x, y)?;
+        Some(&mut self[position])
+    }
+
+    /// The positions of the cells `offsets` away from `(x, y)`, in the same order.
+    fn offset_positions(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &[(isize, isize)],
+    ) -> Vec<(usize, usize)> {
+        let (x, y) = (x as isize, y as isize);
+        offsets
+            .iter()
+            .filter_map(|(dx, dy)| self.position(x + dx, y + dy))
+            .collect()
+    }
+
+    /// The positions of the up to 4 orthogonal neighbours of `(x, y)`: up, left, down and
+    /// right. Edges are crossed in toroidal matrices, where those less than 3 cells wide or high
+    /// give some neighbours twice, or the cell itself. The background of infinite ones has no
+    /// position, so it is left out like past the edges of bounded ones.
+    pub fn rook_neighbor_indices(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        self.offset_positions(x, y, &[(0, -1), (-1, 0), (0, 1), (1, 0)])
+            .into_iter()
+    }
+
+    /// The positions of the up to 8 neighbours of `(x, y)`, diagonal ones included, with the
+    /// same topology rules as [`rook_neighbor_indices`](Self::rook_neighbor_indices).
+    pub fn neighbor_indices(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        let offsets = [
+            (-1, 0),
+            (1, 0),
+            (0, -1),
+            (-1, -1),
+            (1, -1),
+            (0, 1),
+            (-1, 1),
+            (1, 1),
+        ];
+        self.offset_positions(x, y, &offsets)
+    }
+
+    /// The orthogonal neighbours of `(x, y)` along with their positions, in the order of
+    /// [`rook_neighbor_indices`](Self::rook_neighbor_indices).
+    pub fn rook_neighbors(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.rook_neighbor_indices(x, y)
+            .map(move |position| (position, &self[position]))
+    }
+
+    /// All the neighbours of `(x, y)` along with their positions, in the order of
+    /// [`neighbor_indices`](Self::neighbor_indices).
+    pub fn neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.neighbor_indices(x, y)
+            .into_iter()
+            .map(move |position| (position, &self[position]))
+    }
+
+    /// Whether the cell at `(x, y)` is lower than all its orthogonal neighbours.
+    pub fn is_local_minimum(&self, x: usize, y: usize) -> bool
+    where
+        T: PartialOrd,
+    {
+        let cell = &self[(x, y)];
+        self.rook_neighbors(x, y).all(|(_, n)| cell < n)
+    }
+
+    /// Whether the cell at `(x, y)` is higher than all its orthogonal neighbours.
+    pub fn is_local_maximum(&self, x: usize, y: usize) -> bool
+    where
+        T: PartialOrd,
+    {
+        let cell = &self[(x, y)];
+        self.rook_neighbors(x, y).all(|(_, n)| cell > n)
+    }
+
+    /// The positions of the [local minima](Self::is_local_minimum), row by row.
+    pub fn local_minima(&self) -> Vec<(usize, usize)>
+    where
+        T: PartialOrd,
+    {
+        self.iter_coords()
+            .filter(|&(x, y)| self.is_local_minimum(x, y))
+            .collect()
+    }
+
+    /// The positions of the [local maxima](Self::is_local_maximum), row by row.
+    pub fn local_maxima(&self) -> Vec<(usize, usize)>
+    where
+        T: PartialOrd,
+    {
+        self.iter_coords()
+            .filter(|&(x, y)| self.is_local_maximum(x, y))
+            .collect()
+    }
+
+    /// The rows, from the top.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, a width that leaves no cells anyway
+        self.vec.chunks(self.width.max(1))
+    }
+
+    /// The columns from the left, each one from the top.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let width = self.width;
+        (0..width).map(move |x| self.vec.iter().skip(x).step_by(width))
+    }
+
+    /// The positions of all the cells, row by row. The matrix is not borrowed, so that it can be
+    /// changed along the way.
+    pub fn iter_coords(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// The cells along with their positions, row by row.
+    pub fn iter_with_coords(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.iter_coords().zip(&self.vec)
+    }
+
+    /// The cells along with their positions, row by row.
+    pub fn iter_mut_with_coords(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.iter_coords().zip(&mut self.vec)
+    }
+
+    /// The positions of the cells for which `predicate` holds, row by row.
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter_with_coords()
+            .filter_map(move |(position, cell)| predicate(cell).then_some(position))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of cells.
+    pub fn len(&self) -> usize {
+        self.height * self.width
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Inserts the rows of `other`, which must be as wide, before row `at`.
+    pub fn insert_row_at(&mut self, mut other: Matrix<T>, at: usize) {
+        assert!(other.width() == self.width());
+        let idx = at * self.width();
+        self.height += other.height;
+        let mut after = self.vec.split_off(idx);
+        self.vec.append(&mut other.vec);
+        self.vec.append(&mut after);
+    }
+
+    /// Surrounds the matrix with `n` rows and columns of `with` on every side, keeping its
+    /// topology.
+    pub fn expand_contour(self, n: usize, with: T) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height() + n * 2;
+        let width = self.width() + n * 2;
+
+        let mut new = Matrix {
+            vec: vec![with; height * width],
+            height,
+            width,
+            topology: self.topology.clone(),
+        };
+        for x in 0..self.width {
+            for y in 0..self.height {
+                new[(x + n, y + n)] = self[(x, y)].clone();
+            }
+        }
+        new
+    }
+
+    /// Makes a matrix of default cells.
+    pub fn new_default(width: usize, height: usize) -> Self
+    where
+        T: Default + Clone,
+    {
+        Matrix::new(vec![T::default(); height * width], width, height)
+    }
+
+    /// Swaps the cells at positions `a` and `b`.
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let idx_a = a.1 * self.width() + a.0;
+        let idx_b = b.1 * self.width() + b.0;
+        self.vec.swap(idx_a, idx_b)
+    }
+
+    /// A `width` by `height` matrix whose cell at `(x, y)` is the one of this matrix at
+    /// `source(x, y)`, keeping the topology.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y)));
+        Matrix::new(
+            cells.map(|(x, y)| self[source(x, y)].clone()),
+            width,
+            height,
+        )
+        .with_topology(self.topology.clone())
+    }
+
+    /// Swaps rows and columns, mirroring the matrix along its diagonal from the top left corner.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Turns the matrix a quarter clockwise, as printed: the left column becomes the top row.
+    pub fn rotate_90(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    pub fn rotate_180(&self) -> Self
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.width, self.height);
+        self.remap(width, height, |x, y| (width - 1 - x, height - 1 - y))
+    }
+
+    /// Turns the matrix a quarter counterclockwise, as printed: the top row becomes the left
+    /// column.
+    pub fn rotate_270(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Mirrors the matrix left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    /// Mirrors the matrix top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.width, height, |x, y| (x, height - 1 - y))
+    }
+
+    /// A view of the cells in `rect`, which must fit in the matrix.
+    pub fn sub_matrix(&self, rect: Rect) -> SubMatrix<'_, T> {
+        assert!(
+            rect.x + rect.width <= self.width && rect.y + rect.height <= self.height,
+            "{:?} does not fit in a {}x{} matrix",
+            rect,
+            self.width,
+            self.height
+        );
+        SubMatrix { matrix: self, rect }
+    }
+
+    /// Lays `across` by `down` copies of the matrix side by side, each cell of the copy at
+    /// `(tile_x, tile_y)` among them being turned into `map((tile_x, tile_y), cell)`. The result
+    /// is bounded.
+    pub fn tile<U>(
+        &self,
+        across: usize,
+        down: usize,
+        mut map: impl FnMut((usize, usize), &T) -> U,
+    ) -> Matrix<U> {
+        let (width, height) = (self.width * across, self.height * down);
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let tile = (x / self.width, y / self.height);
+                cells.push(map(tile, &self[(x % self.width, y % self.height)]));
+            }
+        }
+        Matrix::new(cells, width, height)
+    }
+
+    /// Folds the right part of the matrix over the left one along column `at`, which goes
+    /// away. Each cell the right part lands on becomes `merge(cell, landing)`. The right part
+    /// must not be wider than the left one.
+    pub fn fold_along_column(&self, at: usize, mut merge: impl FnMut(&T, &T) -> T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(
+            at < self.width && self.width - at - 1 <= at,
+            "cannot fold a matrix {} wide along column {}",
+            self.width,
+            at
+        );
+        let mut folded = self.remap(at, self.height, |x, y| (x, y));
+        for y in 0..self.height {
+            for x in at + 1..self.width {
+                let target = (2 * at - x, y);
+                folded[target] = merge(&folded[target], &self[(x, y)]);
+            }
+        }
+        folded
+    }
+
+    /// Folds the bottom part of the matrix over the top one along row `at`, which goes away.
+    /// Each cell the bottom part lands on becomes `merge(cell, landing)`. The bottom part must
+    /// not be higher than the top one.
+    pub fn fold_along_row(&self, at: usize, mut merge: impl FnMut(&T, &T) -> T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(
+            at < self.height && self.height - at - 1 <= at,
+            "cannot fold a matrix {} high along row {}",
+            self.height,
+            at
+        );
+        let mut folded = self.remap(self.width, at, |x, y| (x, y));
+        for y in at + 1..self.height {
+            for x in 0..self.width {
+                let target = (x, 2 * at - y);
+                folded[target] = merge(&folded[target], &self[(x, y)]);
+            }
+        }
+        folded
+    }
+}
+
+/// A rectangle of cells borrowed from a [`Matrix`], see [`Matrix::sub_matrix`].
+#[derive(Debug, Clone, Copy)]
+pub struct SubMatrix<'a, T> {
+    matrix: &'a Matrix<T>,
+    rect: Rect,
+}
+
+impl<'a, T> SubMatrix<'a, T> {
+    pub fn width(&self) -> usize {
+        self.rect.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.rect.height
+    }
+
+    /// The cell at `(x, y)` of the view, `None` outside of it whatever the topology of the
+    /// matrix.
+    pub fn get(&self, x: isize, y: isize) -> Option<&'a T> {
+        let inside = (0..self.rect.width as isize).contains(&x)
+            && (0..self.rect.height as isize).contains(&y);
+        let matrix = self.matrix;
+        inside.then(|| &matrix[(self.rect.x + x as usize, self.rect.y + y as usize)])
+    }
+
+    /// The rows of the view, from the top.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let (matrix, rect) = (self.matrix, self.rect);
+        (rect.y..rect.y + rect.height).map(move |y| {
+            let start = y * matrix.width + rect.x;
+            &matrix.vec[start..start + rect.width]
+        })
+    }
+
+    /// Copies the cells of the view into a bounded matrix.
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let cells = self.rows().flat_map(|row| row.iter().cloned());
+        Matrix::new(cells, self.rect.width, self.rect.height)
+    }
+}
+
+impl<T> ops::Index<(usize, usize)> for SubMatrix<'_, T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        if x >= self.rect.width || y >= self.rect.height {
+            panic!(
+                "Index ({}, {}) out of range for SubMatrix with size ({}, {})",
+                y, x, self.rect.height, self.rect.width
+            );
+        }
+        &self.matrix[(self.rect.x + x, self.rect.y + y)]
+    }
+}
+
+impl<T> fmt::Debug for Matrix<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut count = 0;
+        writeln!(f)?;
+        for item in &self.vec {
+            write!(f, "{:?}", item)?;
+            count += 1;
+            if count == self.width {
+                count = 0;
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        if x > self.width - 1 || y > self.height - 1 {
+            panic!(
+                "Index ({}, {}) out of range for Matrix with size ({}, {})",
+                y, x, self.height, self.width
+            );
+        }
+        &self.vec[x + y * self.width]
+    }
+}
+
+impl<T> ops::IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        if x > self.width - 1 || y > self.height - 1 {
+            panic!(
+                "Index ({}, {}) out of range for Matrix with size ({}, {})",
+                y, x, self.height, self.width
+            );
+        }
+        &mut self.vec[x + y * self.width]
+    }
+}
+
+impl<T> ops::Index<(&usize, &usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (&usize, &usize)) -> &Self::Output {
+        if *x > self.width - 1 || *y > self.height - 1 {
+            panic!(
+                "Index ({}, {}) out of range for Matrix with size ({}, {})",
+                y, x, self.height, self.width
+            );
+        }
+        &self.vec[x + y * self.width]
+    }
+}
+
+impl<T> ops::IndexMut<(&usize, &usize)> for Matrix<T> {
+    fn index_mut(&mut self, (x, y): (&usize, &usize)) -> &mut Self::Output {
+        if *x > self.width - 1 || *y > self.height - 1 {
+            panic!(
+                "Index ({}, {}) out of range for Matrix with size ({}, {})",
+                y, x, self.height, self.width
+            );
+        }
+        &mut self.vec[x + y * self.width]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::examples::example;
+    use crate::Part;
+    #[test]
+    fn test_swap() {
+        let items = 0..6;
+        let mut matrix = Matrix::new(items, 3, 2);
+        matrix.swap((0,0), (0,1));
+        assert_eq!(matrix[(0,0)], 3);
+        assert_eq!(matrix[(0,1)], 0);
+    }
+
+    #[test]
+    fn neighbors() {
+        let matrix = Matrix::new(0..6, 3, 2);
+        let neighbors: Vec<_> = matrix.rook_neighbors(0, 1).collect();
+        assert_eq!(neighbors, [((0, 0), &0), ((1, 1), &4)]);
+        let values: Vec<_> = matrix.neighbors(1, 0).map(|(_, &v)| v).collect();
+        assert_eq!(values, [0, 2, 4, 3, 5]);
+    }
+
+    #[test]
+    fn topologies() {
+        let bounded = Matrix::new(0..6, 3, 2);
+        assert_eq!((bounded.get(-1, 0), bounded.get(2, 1)), (None, Some(&5)));
+        assert_eq!(bounded.neighbor_indices(0, 0), [(1, 0), (0, 1), (1, 1)]);
+
+        let mut torus = bounded.clone().with_topology(Topology::Toroidal);
+        assert_eq!(
+            (torus.get(-1, 0), torus.get(3, 3), torus.get(-4, -5)),
+            (Some(&2), Some(&3), Some(&5))
+        );
+        assert_eq!(torus.position(4, -1), Some((1, 1)));
+        let neighbors: Vec<_> = torus.rook_neighbor_indices(0, 0).collect();
+        assert_eq!(neighbors, [(0, 1), (2, 0), (0, 1), (1, 0)]);
+        assert_eq!(torus.neighbor_indices(1, 1).len(), 8);
+        *torus.get_mut(-1, -1).unwrap() = 9;
+        assert_eq!(torus[(2, 1)], 9);
+
+        let mut infinite = bounded.with_topology(Topology::Infinite(7));
+        assert_eq!(
+            (infinite.get(-1, 0), infinite.get(1, 1)),
+            (Some(&7), Some(&4))
+        );
+        assert_eq!(infinite.get_mut(3, 0), None);
+        assert_eq!(infinite.rook_neighbor_indices(0, 0).count(), 2);
+        let expanded = infinite.expand_contour(1, 7);
+        assert_eq!(expanded.topology(), &Topology::Infinite(7));
+        assert_eq!(expanded.get(0, 0), expanded.get(-9, 9));
+    }
+
+    #[test]
+    fn transforms() {
+        // 0 1 2
+        // 3 4 5
+        let matrix = Matrix::new(0..6, 3, 2);
+        let cells = |m: Matrix<i32>| (m.width(), m.height(), m.vec);
+        assert_eq!(cells(matrix.transpose()), (2, 3, vec![0, 3, 1, 4, 2, 5]));
+        assert_eq!(cells(matrix.rotate_90()), (2, 3, vec![3, 0, 4, 1, 5, 2]));
+        assert_eq!(cells(matrix.rotate_180()), (3, 2, vec![5, 4, 3, 2, 1, 0]));
+        assert_eq!(cells(matrix.rotate_270()), (2, 3, vec![2, 5, 1, 4, 0, 3]));
+        assert_eq!(
+            cells(matrix.flip_horizontal()),
+            (3, 2, vec![2, 1, 0, 5, 4, 3])
+        );
+        assert_eq!(
+            cells(matrix.flip_vertical()),
+            (3, 2, vec![3, 4, 5, 0, 1, 2])
+        );
+        assert_eq!(matrix.rotate_90().rotate_270(), matrix);
+        assert_eq!(matrix.rotate_90().rotate_90(), matrix.rotate_180());
+        let torus = matrix.clone().with_topology(Topology::Toroidal);
+        assert_eq!(torus.rotate_90().topology(), &Topology::Toroidal);
+    }
+
+    #[test]
+    fn sub_matrix() {
+        let matrix = Matrix::new(0..12, 4, 3);
+        let view = matrix.sub_matrix(Rect {
+            x: 1,
+            y: 1,
+            width: 2,
+            height: 2,
+        });
+        assert_eq!((view.width(), view.height(), view[(1, 0)]), (2, 2, 6));
+        assert_eq!((view.get(0, 1), view.get(2, 0)), (Some(&9), None));
+        assert_eq!(view.rows().collect::<Vec<_>>(), [[5, 6], [9, 10]]);
+        assert_eq!(view.to_matrix(), Matrix::new([5, 6, 9, 10], 2, 2));
+    }
+
+    #[test]
+    fn tile() {
+        let matrix = Matrix::new([1, 9], 2, 1);
+        let tiled = matrix.tile(2, 2, |(x, y), &v| (v + x + 2 * y - 1) % 9 + 1);
+        assert_eq!((tiled.width(), tiled.height()), (4, 2));
+        assert_eq!(tiled.vec, [1, 9, 2, 1, 3, 2, 4, 3]);
+    }
+
+    #[test]
+    fn folds() {
+        // 1 0 0 0 1
+        // 0 1 0 0 0
+        let matrix = Matrix::new([1, 0, 0, 0, 1, 0, 1, 0, 0, 0], 5, 2);
+        let folded = matrix.fold_along_column(2, |a, b| a | b);
+        assert_eq!(folded, Matrix::new([1, 0, 0, 1], 2, 2));
+        let folded = matrix.fold_along_column(3, |a, b| a + b);
+        assert_eq!(folded, Matrix::new([1, 0, 1, 0, 1, 0], 3, 2));
+        let folded = matrix.fold_along_row(1, |a, b| a | b);
+        assert_eq!(folded, Matrix::new([1, 0, 0, 0, 1], 5, 1));
+    }
+
+    #[test]
+    fn iterators() {
+        // 0 1 2
+        // 3 4 5
+        let mut matrix = Matrix::new(0..6, 3, 2);
+        assert_eq!(matrix.rows().collect::<Vec<_>>(), [[0, 1, 2], [3, 4, 5]]);
+        let columns: Vec<Vec<_>> = matrix.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, [[0, 3], [1, 4], [2, 5]]);
+        let coords: Vec<_> = matrix.iter_coords().collect();
+        assert_eq!(coords, [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+        let cells: Vec<_> = matrix.iter_with_coords().collect();
+        assert_eq!(cells[4], ((1, 1), &4));
+        for ((x, y), cell) in matrix.iter_mut_with_coords() {
+            *cell = 10 * y + x;
+        }
+        assert_eq!(matrix.vec, [0, 1, 2, 10, 11, 12]);
+        let odd: Vec<_> = matrix.positions(|cell| cell % 2 == 1).collect();
+        assert_eq!(odd, [(1, 0), (1, 1)]);
+
+        let empty: Matrix<u8> = Matrix::new([], 0, 0);
+        assert_eq!(empty.rows().count() + empty.columns().count(), 0);
+    }
+
+    #[test]
+    fn extrema() {
+        let matrix = Matrix::parse_grid("2199\n3987\n9856", digit).unwrap();
+        assert_eq!(matrix.local_minima(), [(1, 0), (2, 2)]);
+        // Ties are not extrema
+        assert_eq!(matrix.local_maxima(), [(1, 1), (0, 2)]);
+        assert!(!matrix.is_local_maximum(3, 0));
+    }
+
+    fn digit(c: char) -> Result<u32, &'static str> {
+        c.to_digit(10).ok_or("expected a digit")
+    }
+
+    #[test]
+    fn parse_grid() {
+        let matrix = Matrix::parse_grid("\r\n  12 \r\n  34\r\n  56\r\n\n", digit).unwrap();
+        assert_eq!(matrix, Matrix::new(1..=6, 2, 3));
+        let matrix = Matrix::parse_grid("\t 12\n\t  3\n\t 45", Ok::<_, String>).unwrap();
+        assert_eq!((matrix.width(), matrix.height()), (2, 3));
+        assert_eq!(matrix[(0, 1)], ' ');
+        let matrix = Matrix::parse_grid("12\n        34\n        56", digit).unwrap();
+        assert_eq!(matrix, Matrix::new(1..=6, 2, 3));
+
+        let err = Matrix::parse_grid("12\n3x\n", digit).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+        assert_eq!(err.message, "expected a digit");
+        let err = Matrix::parse_grid("  12\n  345\n", digit).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "345"));
+        let err = Matrix::parse_grid("12\n\n34\n", digit).unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.message, "blank line inside the grid");
+        assert!(Matrix::parse_grid(" \n", digit).is_err());
+    }
+
+    #[test]
+    fn parse_indented_grid() {
+        // Grids written inline in tests get their rows indented along with the code
+        let indented = Matrix::parse_grid(
+            "5483143223
+        2745854711
+        5264556173
+        6141336146
+        6357385478
+        4167524645
+        2176841721
+        6882881134
+        4846848554
+        5283751526",
+            digit,
+        )
+        .unwrap();
+        let octopuses = example(2021, 11, Part::One, "1656");
+        assert_eq!(indented, Matrix::parse_grid(octopuses, digit).unwrap());
+    }
+
+    #[test]
+    fn parse_grid_padded() {
+        let matrix = Matrix::parse_grid_padded("123\r\n 4\n5", '0', digit);
+        assert!(matrix.is_err());
+        let matrix = Matrix::parse_grid_padded("123\r\n04\n5", '0', digit).unwrap();
+        assert_eq!(matrix.vec, [1, 2, 3, 0, 4, 0, 5, 0, 0]);
+        let err = Matrix::parse_grid_padded("12\n1", 'x', digit).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = Matrix::parse_grid_padded("12\n345", '0', digit).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "5"));
+    }
+}
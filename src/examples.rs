@@ -0,0 +1,84 @@
+use crate::solution::Part;
+
+/// An example from a puzzle text, with the answers the text gives for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub input: &'static str,
+    pub first: Option<&'static str>,
+    pub second: Option<&'static str>,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.first,
+            Part::Two => self.second,
+        }
+    }
+}
+
+/// The examples of `day` in `year`, in the order the puzzle text gives them. Empty for days
+/// without any, like 2021's day 24 whose program only comes with the real input.
+pub fn examples(year: u16, day: u8) -> &'static [Example] {
+    crate::year(year).map_or(&[], |year| (year.examples)(day))
+}
+
+/// The input of the only example of `day` in `year` to which the puzzle text gives `answer` for
+/// `part`, so that tests say which example they take rather than where it sits in the table.
+#[cfg(test)]
+pub(crate) fn example(year: u16, day: u8, part: Part, answer: &str) -> &'static str {
+    let mut found = examples(year, day)
+        .iter()
+        .filter(|example| example.expected(part) == Some(answer));
+    match (found.next(), found.next()) {
+        (Some(example), None) => example.input,
+        _ => panic!(
+            "{} day {} has no single example answering {} to part {}",
+            year, day, answer, part
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::YEARS;
+
+    /// Parts taking seconds on their examples in debug builds
+    const SLOW: [(u16, u8, Part); 1] = [(2021, 23, Part::Two)];
+
+    fn check(slow: bool) {
+        for year in YEARS {
+            for solution in year.solutions {
+                let day = solution.day();
+                for (i, example) in examples(year.year, day).iter().enumerate() {
+                    let parsed = solution.parse(example.input).unwrap();
+                    for part in Part::BOTH {
+                        if SLOW.contains(&(year.year, day, part)) != slow {
+                            continue;
+                        }
+                        if let Some(expected) = example.expected(part) {
+                            let name = format!("{} day {} example {}", year.year, day, i + 1);
+                            let answer = match parsed.solve(part) {
+                                Ok(answer) => answer.to_string(),
+                                Err(e) => panic!("{} part {}: {}", name, part, e),
+                            };
+                            assert_eq!(answer, expected, "{} part {}", name, part);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn answers() {
+        check(false);
+    }
+
+    #[test]
+    #[ignore]
+    fn slow_answers() {
+        check(true);
+    }
+}
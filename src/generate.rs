@@ -0,0 +1,101 @@
+use std::ops::RangeInclusive;
+
+/// A small deterministic random number generator (SplitMix64), so that a seed always gives the
+/// same inputs whatever the platform.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+        let span = end.abs_diff(start).wrapping_add(1);
+        // The bias of the modulo is negligible for the small spans puzzles use
+        let offset = if span == 0 {
+            self.next_u64()
+        } else {
+            self.next_u64() % span
+        };
+        start.wrapping_add(offset as i64)
+    }
+
+    /// An index below `len`, which must not be 0.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..=len as i64 - 1) as usize
+    }
+
+    /// Whether an event of probability `p` happens.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// Makes random but valid inputs for a day, to stress test and benchmark it beyond the examples.
+#[derive(Debug, Clone, Copy)]
+pub struct Generator {
+    /// Size of the real puzzle inputs, in `unit`s. 0 for days whose inputs all have the same
+    /// size, whose generator then ignores the size it is given
+    pub size: usize,
+    pub unit: &'static str,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+/// The input generator of `day` in `year`, if it has one.
+pub fn generator(year: u16, day: u8) -> Option<Generator> {
+    crate::year(year).and_then(|year| (year.generator)(day))
+}
+
+/// An input of `day` in `year` made from `seed`, of the size of the real ones unless `size` is
+/// given. Solvers are only promised to handle the real size: larger inputs may take a long
+/// time, or overflow the answers.
+pub fn generate(year: u16, day: u8, seed: u64, size: Option<usize>) -> Option<String> {
+    let generator = generator(year, day)?;
+    let size = size.unwrap_or(generator.size);
+    Some((generator.generate)(&mut Rng::new(seed), size))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rng() {
+        let numbers: Vec<_> = (0..100).map(|_| Rng::new(7).range(-3..=3)).collect();
+        assert!(numbers.windows(2).all(|w| w[0] == w[1]));
+
+        let mut rng = Rng::new(7);
+        let numbers: Vec<_> = (0..1000).map(|_| rng.range(-3..=3)).collect();
+        assert!((-3..=3).all(|n| numbers.contains(&n)));
+        assert!(numbers.iter().all(|n| (-3..=3).contains(n)));
+        assert!(rng.range(i64::MIN..=i64::MAX) != rng.range(i64::MIN..=i64::MAX));
+
+        let mut items: Vec<_> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -1,26 +1,19 @@
-pub mod day1;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day2;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-mod matrix;
+pub mod answer;
+pub mod api;
+pub mod cancel;
+pub mod common;
+pub mod examples;
+pub mod generate;
+pub mod memory;
+pub mod ocr;
+pub mod parse;
+pub mod solution;
+pub mod y2021;
+
+pub use answer::Answer;
+pub use api::{available_days, solve, SolveError};
+pub use examples::{examples, Example};
+pub use parse::{ParseError, ParseResult};
+pub use solution::{
+    solution, year, DynSolution, Parsed, Part, PartError, PartResult, Solution, Year, YEARS,
+};
@@ -1,137 +1,407 @@
+mod cli;
+
 use advent_2021::*;
-use clap::Parser;
-use std::borrow::Borrow;
-use std::fmt::Display;
-use std::fs;
-use std::time::Instant;
+use anyhow::{bail, Result};
+use clap::{Parser, Subcommand};
+use cli::input::Source;
+use cli::output::{Format, Record, Report};
+use cli::selection::{Days, Only, Selection};
+use cli::verify::{Answers, Verdict};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Parser)]
 struct Args {
-    #[clap(short, long)]
-    day: Option<u8>,
+    /// Year of the puzzles to run
+    #[clap(short, long, global(true), default_value_t = 2021)]
+    year: u16,
+
+    /// Days to run, like `3-7,12,20-25`
+    #[clap(short, long, global(true))]
+    day: Option<Days>,
+
+    /// Only run this part of each day
+    #[clap(short, long, global(true))]
+    part: Option<Part>,
+
+    /// Run exactly these `day:part` pairs, like `15:2,20-25:1`
+    #[clap(
+        long,
+        global(true),
+        multiple_occurrences(true),
+        use_value_delimiter(true),
+        conflicts_with_all(&["day", "part"])
+    )]
+    only: Vec<Only>,
+
+    /// Read the input from this file instead, `-` meaning stdin. Needs a single day
+    #[clap(short, long, global(true))]
+    input: Option<PathBuf>,
+
+    /// Run the examples of the puzzle texts instead of the inputs, the first one unless `N` is
+    /// given. `verify` then checks the answers the texts give for them
+    #[clap(long, global(true), value_name = "N", conflicts_with = "input")]
+    example: Option<Option<usize>>,
+
+    /// Directory holding the `dayN.txt` inputs, `input/YEAR` by default
+    #[clap(long, global(true))]
+    input_dir: Option<PathBuf>,
+
+    /// How to print the answers and timings of a run, `text` by default
+    #[clap(long, global(true), arg_enum)]
+    format: Option<Format>,
+
+    /// Number of threads solving days and parts side by side of a run, 0 meaning one per CPU.
+    /// 1 by default
+    #[clap(short, long, global(true))]
+    jobs: Option<usize>,
+
+    /// Give up on a part after this long, like `30s` or `500ms`, and go on with the next one.
+    /// Only for runs, `watch` and `serve`
+    #[clap(long, global(true), parse(try_from_str = cli::watchdog::parse_duration))]
+    timeout: Option<Duration>,
+
+    /// File every run from the input directory appends its answers and timings to
+    #[clap(long, global(true), default_value = "history.jsonl")]
+    history: PathBuf,
+
+    /// Do not append this run to the history file
+    #[clap(long)]
+    no_history: bool,
+
+    #[clap(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Time the parse, part 1 and part 2 steps separately over many runs
+    Bench {
+        /// Number of measured runs of each step
+        #[clap(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Number of unmeasured runs of each step beforehand
+        #[clap(short, long, default_value_t = 1)]
+        warmup: usize,
+    },
+    /// Check the answers against a file of known answers, failing on any mismatch
+    Verify {
+        /// TOML file with a `[dayN]` table of `part1`/`part2` answers per day. Defaults to
+        /// `answers.toml` in the input directory
+        #[clap(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Re-run a single day whenever its input changes, showing how answers and timings moved
+    Watch {
+        /// Also watch `src/yYEAR/dayN.rs`, rebuilding and restarting when it changes
+        #[clap(long)]
+        rebuild: bool,
+
+        /// How often to look for changes
+        #[clap(
+            long,
+            default_value = "500ms",
+            parse(try_from_str = cli::watchdog::parse_duration)
+        )]
+        interval: Duration,
+    },
+    /// Create `src/yYEAR/dayN.rs` from a template and register it, refusing to overwrite anything
+    NewDay {
+        #[clap(value_name = "DAY")]
+        number: u8,
+    },
+    /// Answer `POST /solve/{day}/{part}` requests on the local host, the body being the input
+    Serve {
+        /// Port to listen on
+        #[clap(long, default_value_t = 8080)]
+        port: u16,
+    },
+    /// Make random but valid inputs, to stdout for a single day or as `dayN.txt` files
+    Generate {
+        /// Seed of the inputs, printed to stderr when not given so they can be made again
+        #[clap(long)]
+        seed: Option<u64>,
 
-    #[clap(short, long)]
-    part: Option<u8>,
+        /// Size of the inputs, in the units `--list` gives. Defaults to that of the real ones
+        #[clap(long)]
+        size: Option<usize>,
+
+        /// Directory to write the inputs to, needed for several days
+        #[clap(long)]
+        out: Option<PathBuf>,
+
+        /// Overwrite the inputs already in `--out`
+        #[clap(long)]
+        force: bool,
+
+        /// List the days having a generator, with the size of their real inputs
+        #[clap(long, conflicts_with_all(&["seed", "size", "out", "force"]))]
+        list: bool,
+    },
+    /// Look back at the runs stored in the history file
+    History {
+        #[clap(subcommand)]
+        command: HistoryCommand,
+    },
 }
-fn run_with_two_gen<G, D, K: ?Sized>(
-    p1: impl Fn(&K) -> D,
-    p2: impl Fn(&K) -> D,
-    gen1: impl Fn(&str) -> G,
-    gen2: impl Fn(&str) -> G,
-    input_path: &str,
-) where
-    G: Borrow<K>,
-    D: Display,
-{
-    let input = &fs::read_to_string(input_path).expect("correct path");
-    let built1 = gen1(input);
-    println!("first: {}", p1(built1.borrow()));
-    let built2 = gen2(input);
-    println!("second: {}", p2(built2.borrow()));
+
+#[derive(Debug, Subcommand)]
+enum HistoryCommand {
+    /// Compare the last run to the ones before it, failing on slower parts or changed answers
+    Compare {
+        /// Number of earlier runs whose median time is the reference
+        #[clap(short, long, default_value_t = 5)]
+        window: usize,
+
+        /// How much slower than the median a part may get, in percent
+        #[clap(short, long, default_value_t = 10.0)]
+        threshold: f64,
+    },
 }
 
-fn run_with_gen<G, D, K: ?Sized>(
-    p1: impl Fn(&K) -> D,
-    p2: impl Fn(&K) -> D,
-    gen: impl Fn(&str) -> G,
-    input_path: &str,
-) where
-    G: Borrow<K>,
-    D: Display,
-{
-    let input = &fs::read_to_string(input_path).expect("correct path");
-    let built = gen(input);
-    println!("first: {}", p1(built.borrow()));
-    println!("second: {}", p2(built.borrow()));
+/// Appends the solved parts of a run to the history. Runs on other inputs than the input
+/// directory would not be comparable, so they are left out.
+fn record_history(args: &Args, source: &Source, records: &[Record]) {
+    if args.no_history || !matches!(source, Source::Dir(_)) {
+        return;
+    }
+    let run = cli::history::Run::new(args.year, records);
+    if let Err(e) = cli::history::append(&args.history, &run) {
+        eprintln!("warning: {:#}", e);
+    }
 }
 
-fn run<D: Display, F>(p1: F, p2: impl Fn(&str) -> D, input_path: &str)
-where
-    F: Fn(&str) -> D,
-{
-    let input = fs::read_to_string(input_path).expect("correct path");
-    let input_clone = input.clone();
-    println!("first: {}", p1(&input_clone));
-    println!("second: {}", p2(&input));
+fn verify(
+    solution: &dyn DynSolution,
+    selection: &Selection,
+    answers: &Answers,
+    input: &str,
+) -> ParseResult<bool> {
+    let d = solution.day();
+    println!("\n day: {}", d);
+    let parsed = solution.parse(input)?;
+    let mut ok = true;
+    for part in selection.parts(d) {
+        let verdict = match parsed.solve(part) {
+            Ok(answer) => cli::verify::check(answers, d, part, &answer.to_string()),
+            Err(PartError::NotImplemented) => cli::verify::not_implemented(answers, d, part),
+            Err(e) => cli::verify::error(part, &e.to_string()),
+        };
+        ok &= verdict != Verdict::Fail;
+    }
+    Ok(ok)
 }
 
-fn main() {
-    let args = Args::parse();
-    let range = match args.day {
-        None => 1..=25,
-        Some(d) => d..=d,
+/// Rejects the run options a command has no use for, rather than silently ignoring them.
+fn check_options(args: &Args) -> Result<()> {
+    let (name, takes_timeout) = match &args.command {
+        None => return Ok(()),
+        Some(Command::Bench { .. }) => ("bench", false),
+        Some(Command::Verify { .. }) => ("verify", false),
+        Some(Command::Watch { .. }) => ("watch", true),
+        Some(Command::NewDay { .. }) => ("new-day", false),
+        Some(Command::Serve { .. }) => ("serve", true),
+        Some(Command::Generate { .. }) => ("generate", false),
+        Some(Command::History { .. }) => ("history", false),
     };
+    let unsupported = [
+        ("--format", args.format.is_some()),
+        ("--jobs", args.jobs.is_some()),
+        ("--timeout", args.timeout.is_some() && !takes_timeout),
+    ];
+    match unsupported.iter().find(|(_, given)| *given) {
+        Some((option, _)) => bail!("{} does not support {}", name, option),
+        None => Ok(()),
+    }
+}
 
-    for d in range {
-        println!("\n day: {}", d);
-        let time_start = Instant::now();
-        let i_path = &format!("input/2021/day{}.txt", d);
-        match d {
-            1 => {
-                run_with_gen(day1::first, day1::second, day1::gen, i_path);
-            }
-            2 => {
-                run(day2::first, day2::second, i_path);
-            }
-            3 => {
-                run_with_gen(day3::first, day3::second, day3::gen, i_path);
-            }
-            4 => {
-                run_with_gen(day4::first, day4::second, day4::gen, i_path);
-            }
-            5 => {
-                run(day5::first, day5::second, i_path);
-            }
-            6 => {
-                run(day6::first, day6::second, i_path);
-            }
-            7 => {
-                run_with_gen(day7::first, day7::second, day7::gen, i_path);
-            }
-            8 => {
-                run(day8::first, day8::second, i_path);
-            }
-            9 => {
-                run_with_gen(day9::first, day9::second, day9::gen, i_path);
-            }
-            10 => {
-                run(day10::first, day10::second, i_path);
-            }
-            11 => {
-                run_with_gen(day11::first, day11::second, day11::gen, i_path);
-            }
-            12 => {
-                run_with_gen(day12::first, day12::second, day12::gen, i_path);
-            }
-            13 => {
-                run_with_gen(day13::first, day13::second, day13::gen, i_path);
-            }
-            14 => {
-                run_with_gen(day14::first, day14::second, day14::gen, i_path);
-            }
-            15 => {
-                run_with_two_gen(day15::first, day15::second, day15::gen, day15::gen2, i_path);
-            }
-            16 => {
-                run_with_gen(day16::first, day16::second, day16::hex_to_bin, i_path);
+fn try_main(args: Args) -> Result<bool> {
+    check_options(&args)?;
+    if let Some(Command::NewDay { number }) = &args.command {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        for path in cli::scaffold::new_day(root, args.year, *number)? {
+            println!("wrote {}", path.display());
+        }
+        return Ok(true);
+    }
+    if advent_2021::year(args.year).is_none() {
+        let years: Vec<_> = YEARS.iter().map(|y| y.year.to_string()).collect();
+        bail!(
+            "no solutions for {}, only for {}",
+            args.year,
+            years.join(", ")
+        );
+    }
+    if let Some(Command::Serve { port }) = &args.command {
+        cli::serve::serve(args.year, *port, args.timeout)?;
+        return Ok(true);
+    }
+    let input_dir = args
+        .input_dir
+        .clone()
+        .unwrap_or_else(|| Path::new("input").join(args.year.to_string()));
+    let selection = Selection::new(args.day.as_ref(), args.part, &args.only);
+    if let Some(Command::Generate {
+        seed,
+        size,
+        out,
+        force,
+        list,
+    }) = &args.command
+    {
+        if *list {
+            for day in selection.days() {
+                if let Some(generator) = generate::generator(args.year, day) {
+                    match generator.size {
+                        0 => println!("day {}", day),
+                        size => println!("day {}: {} {}", day, size, generator.unit),
+                    }
+                }
             }
-            17 => run(day17::first, day17::second, i_path),
-            18 => {
-                run(day18::first, day18::second, i_path);
+            return Ok(true);
+        }
+        let seed = seed.unwrap_or_else(|| {
+            let seed = cli::generate::random_seed();
+            eprintln!("seed: {}", seed);
+            seed
+        });
+        let days = selection.days();
+        cli::generate::generate(args.year, &days, seed, *size, out.as_deref(), *force)?;
+        return Ok(true);
+    }
+    if let Some(Command::History { command }) = &args.command {
+        let mut runs = cli::history::load(&args.history)?;
+        runs.retain(|run| run.year == args.year);
+        return match command {
+            HistoryCommand::Compare { window, threshold } => {
+                cli::history::print_compare(&runs, &selection, *window, *threshold)
             }
-            20 => {
-                run(day20::first, day20::second, i_path);
+        };
+    }
+    let source = match args.example {
+        Some(n) => Source::Example {
+            year: args.year,
+            n: n.unwrap_or(1),
+        },
+        None => Source::new(args.input.as_deref(), &input_dir),
+    };
+    source.check_days(selection.days().len())?;
+    if let Some(Command::Watch { rebuild, interval }) = &args.command {
+        let (timeout, interval, rebuild) = (args.timeout, *interval, *rebuild);
+        cli::watch::watch(args.year, &selection, &source, timeout, interval, rebuild)?;
+        return Ok(true);
+    }
+
+    let answers = match &args.command {
+        Some(Command::Verify { .. }) if args.example.is_some() => Some(Answers::examples(
+            args.year,
+            args.example.flatten().unwrap_or(1),
+        )),
+        Some(Command::Verify { answers }) => {
+            let path = answers
+                .clone()
+                .unwrap_or_else(|| input_dir.join("answers.toml"));
+            Some(Answers::load(&path)?)
+        }
+        _ => None,
+    };
+
+    let format = args.format.unwrap_or(Format::Text);
+    let mut report = Report::new(format);
+    let mut ok = true;
+    let jobs = match args.jobs.unwrap_or(1) {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    };
+    if args.command.is_none() && jobs > 1 {
+        let time_start = Instant::now();
+        let days = cli::run::run_parallel(args.year, &selection, &source, jobs, args.timeout);
+        for (day, records) in days {
+            report.push_day(day, records);
+        }
+        let wall_time = time_start.elapsed();
+        let records = report.finish();
+        if format == Format::Text {
+            let cpu_time = cli::run::cpu_time(&records);
+            println!(
+                "\nwall time: {:?}, cpu time: {:?} ({} threads)",
+                wall_time, cpu_time, jobs
+            );
+        }
+        record_history(&args, &source, &records);
+        return Ok(!records.iter().any(Record::failed));
+    }
+    for day in selection.days() {
+        let solution = advent_2021::solution(args.year, day);
+        match (&args.command, solution) {
+            (None, _) => report.push_day(
+                day,
+                cli::run::run(solution, day, &selection, &source, args.timeout),
+            ),
+            (Some(command), Some(solution)) => {
+                if let Some(reason) = source.skipped(day) {
+                    println!("\n day: {}\nskipped: {}", day, reason);
+                    continue;
+                }
+                let input = match source.read(day) {
+                    Ok(input) => input,
+                    Err(e) => {
+                        eprintln!("\nerror: {:#}", e);
+                        ok = false;
+                        continue;
+                    }
+                };
+                let result = match command {
+                    Command::Bench { iterations, warmup } => {
+                        cli::bench::bench(solution, &input, &selection, *iterations, *warmup)
+                            .map(|()| true)
+                    }
+                    Command::Verify { .. } => {
+                        verify(solution, &selection, answers.as_ref().unwrap(), &input)
+                    }
+                    Command::Generate { .. }
+                    | Command::History { .. }
+                    | Command::NewDay { .. }
+                    | Command::Serve { .. }
+                    | Command::Watch { .. } => unreachable!("handled before running days"),
+                };
+                match result {
+                    Ok(passed) => ok &= passed,
+                    Err(e) => {
+                        eprintln!("\nerror: {}: {}", source.name(day), e);
+                        ok = false;
+                    }
+                }
             }
-            21 => {
-                run(day21::first, day21::second, i_path);
+            // An expected answer without a solution to give it is a failure, not a pass
+            (Some(Command::Verify { .. }), None) => {
+                println!("\n day: {}", day);
+                let answers = answers.as_ref().unwrap();
+                for part in selection.parts(day) {
+                    ok &= cli::verify::not_implemented(answers, day, part) != Verdict::Fail;
+                }
             }
-            22 => run(day22::first, day22::second, i_path),
-            23 => run_with_two_gen(day23::first, day23::second, day23::parse, day23::parse_second, i_path),
-            // 24 => run_with_gen(day24::first, day24::second, day24::gen, i_path),
-            25 => run_with_gen(day25::first, day25::second, day25::gen, i_path),
-            _ => {}
+            (Some(_), None) => {}
+        }
+    }
+    if args.command.is_none() {
+        let records = report.finish();
+        record_history(&args, &source, &records);
+        ok &= !records.iter().any(Record::failed);
+    }
+    Ok(ok)
+}
+
+fn main() {
+    match try_main(Args::parse()) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("error: {:#}", e);
+            process::exit(2);
         }
-        let time_end = Instant::now();
-        println!("total time: {:?}", time_end.duration_since(time_start));
     }
 }
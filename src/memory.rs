@@ -0,0 +1,161 @@
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+
+/// Wraps the system allocator to count what every thread allocates. Installed as the global
+/// allocator by the `alloc-stats` feature.
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+struct Counters {
+    allocations: Cell<u64>,
+    bytes: Cell<u64>,
+    /// Memory freed on another thread than the one that allocated it can make this negative
+    live: Cell<i64>,
+    peak: Cell<i64>,
+}
+
+// Const initialized and without destructor, so that using it never allocates
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters {
+            allocations: Cell::new(0),
+            bytes: Cell::new(0),
+            live: Cell::new(0),
+            peak: Cell::new(0),
+        }
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    // Fails while the thread is being torn down, those allocations are not interesting anyway
+    let _ = COUNTERS.try_with(|c| {
+        if allocated > 0 {
+            c.allocations.set(c.allocations.get() + 1);
+            c.bytes.set(c.bytes.get() + allocated as u64);
+        }
+        let live = c.live.get() + allocated as i64 - freed as i64;
+        c.live.set(live);
+        c.peak.set(c.peak.get().max(live));
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size(), 0);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(layout.size(), 0);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record(0, layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size, layout.size());
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// What a piece of code allocated on its thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    /// Total of every allocation, including the memory that was freed since
+    pub bytes: u64,
+    /// Highest amount of memory held at once, on top of what was held before
+    pub peak_bytes: u64,
+}
+
+/// Runs `f` and reports its allocations, or `None` when the `alloc-stats` feature is off.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !cfg!(feature = "alloc-stats") {
+        return (f(), None);
+    }
+    let (allocations, bytes, live) = COUNTERS.with(|c| {
+        c.peak.set(c.live.get());
+        (c.allocations.get(), c.bytes.get(), c.live.get())
+    });
+    let value = f();
+    let stats = COUNTERS.with(|c| AllocStats {
+        allocations: c.allocations.get() - allocations,
+        bytes: c.bytes.get() - bytes,
+        peak_bytes: (c.peak.get() - live).max(0) as u64,
+    });
+    (value, Some(stats))
+}
+
+fn human(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            human(self.bytes),
+            human(self.peak_bytes)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let stats = AllocStats {
+            allocations: 3,
+            bytes: 5 * 1024 * 1024 + 512 * 1024,
+            peak_bytes: 100,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "3 allocations, 5.5 MiB allocated, 100 B peak"
+        );
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn counting() {
+        let (_, stats) = measure(|| {
+            let big = vec![0_u8; 4096];
+            drop(big);
+            let small = vec![0_u8; 1024];
+            small.len()
+        });
+        let stats = stats.unwrap();
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 5120);
+        assert_eq!(stats.peak_bytes, 4096);
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    #[test]
+    fn disabled() {
+        assert_eq!(measure(|| 1), (1, None));
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+const WIDTH: usize = 4;
+const HEIGHT: usize = 6;
+
+pub(crate) const GLYPHS: &[(char, [&str; HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// A letter cell that does not match any known glyph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    /// Index of the letter, starting at 0
+    pub position: usize,
+    /// The cell as drawn, including the column that should separate it from the next letter
+    pub rows: Vec<String>,
+}
+
+impl fmt::Display for UnknownGlyph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown glyph for letter {}:", self.position + 1)?;
+        for row in &self.rows {
+            write!(f, "\n{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for UnknownGlyph {}
+
+/// Reads the capital letters drawn by `dots`, which are 4 columns wide, 6 rows high and separated
+/// by an empty column. Dots are `(x, y)`, with the first letter starting at `x = 0` and the top
+/// row at `y = 0`.
+pub fn read(dots: &HashSet<(usize, usize)>) -> Result<String, UnknownGlyph> {
+    let max_x = dots.iter().map(|p| p.0).max().unwrap_or(0);
+    let max_y = dots.iter().map(|p| p.1).max().unwrap_or(0);
+    let height = HEIGHT.max(max_y + 1);
+
+    (0..=max_x / (WIDTH + 1))
+        .map(|position| {
+            let start = position * (WIDTH + 1);
+            let rows: Vec<String> = (0..height)
+                .map(|y| {
+                    (start..=start + WIDTH)
+                        .map(|x| if dots.contains(&(x, y)) { '#' } else { '.' })
+                        .collect()
+                })
+                .collect();
+            recognize(&rows).ok_or(UnknownGlyph { position, rows })
+        })
+        .collect()
+}
+
+fn recognize(rows: &[String]) -> Option<char> {
+    if rows.len() != HEIGHT || rows.iter().any(|row| !row.ends_with('.')) {
+        return None;
+    }
+    GLYPHS
+        .iter()
+        .find(|(_, glyph)| glyph.iter().zip(rows).all(|(g, row)| row[..WIDTH] == **g))
+        .map(|&(letter, _)| letter)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dots(rows: &[&str]) -> HashSet<(usize, usize)> {
+        rows.iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .filter(|&(_, c)| c == '#')
+                    .map(move |(x, _)| (x, y))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn letters() {
+        let paper = dots(&[
+            "#..#.####.#....###.",
+            "#..#.#....#....#..#",
+            "####.###..#....#..#",
+            "#..#.#....#....###.",
+            "#..#.#....#....#...",
+            "#..#.####.####.#...",
+        ]);
+        assert_eq!(read(&paper), Ok("HELP".to_string()));
+    }
+
+    #[test]
+    fn unknown_glyph() {
+        let paper = dots(&[
+            "####.#####",
+            "#....#...#",
+            "###..#...#",
+            "#....#...#",
+            "#....#...#",
+            "#....#####",
+        ]);
+        let err = read(&paper).unwrap_err();
+        assert_eq!(err.position, 1);
+        assert_eq!(err.rows[0], "#####");
+        assert!(err
+            .to_string()
+            .starts_with("unknown glyph for letter 2:\n#####"));
+    }
+}
@@ -0,0 +1,132 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// An error in a puzzle input, pointing at the offending text.
+///
+/// Lines and columns start at 1, columns count characters rather than bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    /// Builds an error about `at`, which has to be a slice of `input` for the position to be
+    /// found. Otherwise its first occurrence in `input` is used.
+    pub fn at(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = match (at.as_ptr() as usize).checked_sub(start) {
+            Some(offset) if offset + at.len() <= input.len() => offset,
+            _ => input.find(at).unwrap_or(input.len()),
+        };
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        ParseError {
+            line,
+            column,
+            text: at.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error about something missing at the end of `input`.
+    pub fn end(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    /// Moves an error found in `part`, a slice of `input`, to its position in `input`.
+    pub fn within(mut self, input: &str, part: &str) -> Self {
+        let start = Self::at(input, part, "");
+        if self.line == 1 {
+            self.column += start.column - 1;
+        }
+        self.line += start.line - 1;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, ": \"{}\"", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `s`, a slice of `input`, reporting its position on failure.
+pub fn number<T: FromStr>(input: &str, s: &str) -> ParseResult<T> {
+    s.parse()
+        .map_err(|_| ParseError::at(input, s, "expected a number"))
+}
+
+/// Iterates over the characters of `s` along with the slice each one occupies, so that they can
+/// be handed to [`ParseError::at`].
+pub fn chars(s: &str) -> impl Iterator<Item = (char, &str)> {
+    s.char_indices()
+        .map(move |(i, c)| (c, &s[i..i + c.len_utf8()]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn position() {
+        let input = "12\n3x4\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = ParseError::at(input, &line[1..2], "expected a digit");
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.to_string(), "line 2, column 2: expected a digit: \"x\"");
+    }
+
+    #[test]
+    fn position_counts_chars() {
+        let input = "é,b";
+        let (_, b) = chars(input).nth(2).unwrap();
+        assert_eq!(ParseError::at(input, b, "").column, 3);
+    }
+
+    #[test]
+    fn end_and_foreign_slices() {
+        let input = "1\n2\n";
+        let err = ParseError::end(input, "missing folds");
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.to_string(), "line 3, column 1: missing folds");
+
+        let err = ParseError::at(input, "2", "");
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn within() {
+        let input = "a:12\nb:3x\n";
+        let part = &input[7..];
+        let err = ParseError::at(part, &part[1..2], "").within(input, part);
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "x"));
+    }
+
+    #[test]
+    fn numbers() {
+        let input = "7,x";
+        assert_eq!(number::<u32>(input, &input[..1]), Ok(7));
+        let err = number::<u32>(input, &input[2..]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+    }
+}
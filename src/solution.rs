@@ -0,0 +1,189 @@
+use crate::generate::Generator;
+use crate::parse::ParseResult;
+use crate::*;
+use anyhow::{bail, Error};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => bail!("invalid part \"{}\", expected 1 or 2", other),
+        }
+    }
+}
+
+/// Why a part gives no answer for an input that parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PartError {
+    /// The part has no solution yet
+    NotImplemented,
+    /// The solver could not reach an answer, for the reason given
+    Failed(String),
+}
+
+impl fmt::Display for PartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PartError::NotImplemented => write!(f, "not implemented"),
+            PartError::Failed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for PartError {}
+
+pub type PartResult = Result<Answer, PartError>;
+
+/// A puzzle solution: parse the raw input once, then answer both parts from it.
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+
+    fn parse(input: &str) -> ParseResult<Self::Input>;
+    fn first(input: &Self::Input) -> PartResult;
+    fn second(input: &Self::Input) -> PartResult;
+}
+
+/// Object safe counterpart of [`Solution`], so that days with different input types can live
+/// side by side in a [`Year`].
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+    fn parse(&self, input: &str) -> ParseResult<Box<dyn Parsed>>;
+}
+
+/// An input that has already gone through [`Solution::parse`]. Both parts can be solved at the
+/// same time from different threads.
+pub trait Parsed: Send + Sync {
+    fn first(&self) -> PartResult;
+    fn second(&self) -> PartResult;
+
+    fn solve(&self, part: Part) -> PartResult {
+        match part {
+            Part::One => self.first(),
+            Part::Two => self.second(),
+        }
+    }
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S> Parsed for ParsedInput<S>
+where
+    S: Solution,
+    S::Input: Send + Sync,
+{
+    fn first(&self) -> PartResult {
+        S::first(&self.0)
+    }
+
+    fn second(&self) -> PartResult {
+        S::second(&self.0)
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync + 'static,
+    S::Input: Send + Sync,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> ParseResult<Box<dyn Parsed>> {
+        Ok(Box::new(ParsedInput::<S>(S::parse(input)?)))
+    }
+}
+
+/// The solved days of one year, each year module having its own.
+pub struct Year {
+    pub year: u16,
+    /// In order of days
+    pub solutions: &'static [&'static dyn DynSolution],
+    /// See [`examples`](crate::examples())
+    pub examples: fn(u8) -> &'static [Example],
+    /// See [`generator`](crate::generate::generator())
+    pub generator: fn(u8) -> Option<Generator>,
+}
+
+/// Every year with solutions, in order.
+pub static YEARS: &[&Year] = &[&y2021::YEAR];
+
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().copied().find(|y| y.year == year)
+}
+
+pub fn solution(year: u16, day: u8) -> Option<&'static dyn DynSolution> {
+    self::year(year)?
+        .solutions
+        .iter()
+        .copied()
+        .find(|s| s.day() == day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn registry_is_sorted() {
+        let years: Vec<_> = YEARS.iter().map(|y| y.year).collect();
+        assert!(years.windows(2).all(|w| w[0] < w[1]));
+        for year in YEARS {
+            let days: Vec<_> = year.solutions.iter().map(|s| s.day()).collect();
+            let mut sorted = days.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+            assert_eq!(days, sorted);
+        }
+    }
+
+    #[test]
+    fn dispatch() {
+        let parsed = solution(2021, 7)
+            .unwrap()
+            .parse("16,1,2,0,4,2,7,1,2,14")
+            .unwrap();
+        assert_eq!(parsed.first(), Ok(Answer::Int(37)));
+        assert_eq!(parsed.second(), Ok(Answer::Int(168)));
+        assert_eq!(parsed.solve(Part::Two), Ok(Answer::Int(168)));
+        assert!(solution(2021, 19).is_none());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = solution(2021, 7).unwrap().parse("16,1,x").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 6, "x"));
+    }
+
+    #[test]
+    fn parse_part() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!(" 2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+}
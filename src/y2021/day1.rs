@@ -0,0 +1,69 @@
+use crate::parse::{self, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+use itertools::Itertools;
+
+pub fn gen(input: &str) -> ParseResult<Vec<u32>> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| parse::number(input, s))
+        .collect()
+}
+
+pub fn first(input: &[u32]) -> usize {
+    input.iter().tuple_windows().filter(|(a, b)| a < b).count()
+}
+
+pub fn second(input: &[u32]) -> usize {
+    input
+        .iter()
+        .tuple_windows()
+        .map(|(a, b, c)| a + b + c)
+        .tuple_windows()
+        .filter(|(s1, s2)| s1 < s2)
+        .count()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::examples::example;
+    use crate::Part;
+    #[test]
+    fn first() {
+        let input = super::gen(example(2021, 1, Part::One, "7")).unwrap();
+        assert_eq!(super::first(&input), 7);
+    }
+
+    #[test]
+    fn second() {
+        let input = super::gen(example(2021, 1, Part::Two, "5")).unwrap();
+        assert_eq!(super::second(&input), 5);
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = super::gen("199\n2OO\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
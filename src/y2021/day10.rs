@@ -0,0 +1,164 @@
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+
+pub fn gen(input: &str) -> ParseResult<Vec<String>> {
+    let lines: Vec<&str> = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    if lines.is_empty() {
+        return Err(ParseError::end(input, "expected lines of brackets"));
+    }
+    // The second part needs a line that is neither corrupted nor complete
+    let mut incomplete = false;
+    for line in &lines {
+        let mut stack = Vec::new();
+        let mut corrupted = false;
+        for (c, at) in parse::chars(line) {
+            match c {
+                '(' | '[' | '{' | '<' => stack.push(c),
+                ')' | ']' | '}' | '>' => match stack.pop() {
+                    Some(open) => {
+                        corrupted |=
+                            !matches!((open, c), ('(', ')') | ('[', ']') | ('{', '}') | ('<', '>'))
+                    }
+                    // Nothing past the first illegal character is looked at
+                    None if corrupted => {}
+                    None => return Err(ParseError::at(input, at, "nothing is open to close")),
+                },
+                _ => return Err(ParseError::at(input, at, "expected a bracket")),
+            }
+        }
+        incomplete |= !corrupted && !stack.is_empty();
+    }
+    if !incomplete {
+        return Err(ParseError::end(input, "expected an incomplete line"));
+    }
+    Ok(lines.into_iter().map(str::to_string).collect())
+}
+
+pub fn first(input: &[String]) -> u64 {
+    let mut count = 0;
+
+    'outer: for line in input {
+        let mut stack = Vec::new();
+
+        for c in line.chars() {
+            match c {
+                '(' | '[' | '{' | '<' => stack.push(c),
+                ')' | ']' | '}' | '>' => {
+                    let paren = stack.pop().unwrap();
+                    match (paren, c) {
+                        ('(', ')') | ('[', ']') | ('{', '}') | ('<', '>') => {}
+                        (_, ')') => {
+                            count += 3;
+                            continue 'outer;
+                        }
+                        (_, ']') => {
+                            count += 57;
+                            continue 'outer;
+                        }
+                        (_, '}') => {
+                            count += 1197;
+                            continue 'outer;
+                        }
+                        (_, '>') => {
+                            count += 25137;
+                            continue 'outer;
+                        }
+                        (p, s) => unreachable!("{}, {}", p, s),
+                    }
+                }
+                x => unreachable!("unexpected input {}", x),
+            }
+        }
+    }
+    count
+}
+
+pub fn second(input: &[String]) -> u64 {
+    let mut scores = Vec::new();
+    'line: for line in input {
+        let mut stack = Vec::new();
+        for c in line.chars() {
+            match c {
+                '(' | '[' | '{' | '<' => stack.push(c),
+                ')' | ']' | '}' | '>' => {
+                    let paren = stack.pop().unwrap();
+                    match (paren, c) {
+                        ('(', ')') | ('[', ']') | ('{', '}') | ('<', '>') => {}
+                        (_, _) => continue 'line,
+                    }
+                }
+                x => unreachable!("unexpected input {}", x),
+            }
+        }
+
+        let score = stack.iter().rev().fold(0, |score, paren| {
+            let val = match paren {
+                '(' => 1,
+                '[' => 2,
+                '{' => 3,
+                '<' => 4,
+                x => panic!("unexpected input {}", x),
+            };
+            score * 5 + val
+        });
+        scores.push(score);
+    }
+    scores.sort_unstable();
+    scores[scores.len() / 2]
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::examples::example;
+    use crate::Part;
+
+    #[test]
+    fn one() {
+        let input = example(2021, 10, Part::One, "26397");
+        assert_eq!(first(&gen(input).unwrap()), 26397);
+    }
+
+    #[test]
+    fn two() {
+        let input = example(2021, 10, Part::Two, "288957");
+        assert_eq!(second(&gen(input).unwrap()), 288957);
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]a").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 11));
+        let err = gen("  \n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = gen("[<>]\n(>\n)").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert!(gen("[<>]\n(>)\n(").is_ok());
+        let err = gen("[<>]\n(>").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
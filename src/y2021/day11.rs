@@ -1,4 +1,7 @@
-use crate::matrix::Matrix;
+use crate::common::matrix::Matrix;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use crate::PartResult;
 use colored::Colorize;
 use std::fmt;
 
@@ -27,15 +30,10 @@ impl fmt::Debug for Octopus {
     }
 }
 
-pub fn gen(input: &str) -> Matrix<Octopus> {
-    let width = input.chars().position(|c| c == '\n').unwrap();
-    let height = input.lines().count();
-    let vec = input
-        .chars()
-        .filter_map(|ch| ch.to_digit(10))
-        .map(Octopus::new);
-
-    Matrix::new(vec, width, height)
+pub fn gen(input: &str) -> ParseResult<Matrix<Octopus>> {
+    Matrix::parse_grid(input, |c| {
+        c.to_digit(10).map(Octopus::new).ok_or("expected a digit")
+    })
 }
 
 pub fn first(mat: &Matrix<Octopus>) -> u64 {
@@ -48,9 +46,8 @@ pub fn first(mat: &Matrix<Octopus>) -> u64 {
             oct.energy += 1;
         }
         // Check for flashes
-        let mut updated_pos = (0..mat.width()) // Initialize with all positions at the start
-            .flat_map(|x| (0..mat.height()).map(move |y| (x, y)))
-            .collect::<Vec<_>>();
+        // Initialize with all positions at the start
+        let mut updated_pos = mat.iter_coords().collect::<Vec<_>>();
         loop {
             // While there is a flash chain reaction going on...
             let mut new_flashes = false;
@@ -104,17 +101,15 @@ pub fn second(mat: &Matrix<Octopus>) -> u64 {
         while new_flashes {
             new_flashes = false;
             let mut new_flash_indices = Vec::new();
-            for x in 0..mat.width() {
-                for y in 0..mat.height() {
-                    let oct = &mut mat[(x, y)];
-                    if oct.energy > 9 {
-                        oct.energy = 0;
-                        new_flashes = true;
-                        oct.has_flashed = true;
-                        for pos in mat.neighbor_indices(x, y) {
-                            // Write down which octopi will have energy increased becasue of a neighbor flash
-                            new_flash_indices.push(pos);
-                        }
+            for (x, y) in mat.iter_coords() {
+                let oct = &mut mat[(x, y)];
+                if oct.energy > 9 {
+                    oct.energy = 0;
+                    new_flashes = true;
+                    oct.has_flashed = true;
+                    for pos in mat.neighbor_indices(x, y) {
+                        // Write down which octopi will have energy increased becasue of a neighbor flash
+                        new_flash_indices.push(pos);
                     }
                 }
             }
@@ -126,8 +121,6 @@ pub fn second(mat: &Matrix<Octopus>) -> u64 {
                 }
             }
         }
-        //println!("{:?}", mat);
-        //thread::sleep(Duration::from_millis(70));
         let sync_me = mat[(0, 0)].energy;
         if mat.vec.iter().all(|oct| oct.energy == sync_me) {
             break 'outer;
@@ -136,11 +129,32 @@ pub fn second(mat: &Matrix<Octopus>) -> u64 {
     t
 }
 
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Matrix<Octopus>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::examples::example;
+    use crate::Part;
     mod matrix {
-        use crate::day11::Matrix;
+        use crate::y2021::day11::Matrix;
         #[test]
         fn neighbor_indices() {
             let vec = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
@@ -165,32 +179,20 @@ mod test {
 
     #[test]
     fn one() {
-        let mat = gen("5483143223
-        2745854711
-        5264556173
-        6141336146
-        6357385478
-        4167524645
-        2176841721
-        6882881134
-        4846848554
-        5283751526");
+        let mat = gen(example(2021, 11, Part::One, "1656")).unwrap();
 
         assert_eq!(first(&mat), 1656);
     }
     #[test]
     fn two() {
-        let mat = gen("5483143223
-        2745854711
-        5264556173
-        6141336146
-        6357385478
-        4167524645
-        2176841721
-        6882881134
-        4846848554
-        5283751526");
+        let mat = gen(example(2021, 11, Part::Two, "195")).unwrap();
 
         assert_eq!(second(&mat), 195);
     }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("548\n2-4").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
 }
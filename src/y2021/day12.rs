@@ -1,6 +1,12 @@
+use crate::parse::{ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+use once_cell::sync::Lazy;
 use regex::Regex;
 use std::collections::HashMap;
 
+static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\w+)-(\w+)$").unwrap());
+
 #[derive(Debug, Clone)]
 pub struct CaveSystem {
     adjacencies: HashMap<String, Vec<String>>,
@@ -46,10 +52,12 @@ impl CaveSystem {
     }
 }
 
-pub fn gen(input: &str) -> CaveSystem {
-    let re = Regex::new(r"(\w+)-(\w+)").unwrap();
+pub fn gen(input: &str) -> ParseResult<CaveSystem> {
     let mut adjacencies = HashMap::new();
-    for cap in re.captures_iter(input) {
+    for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let cap = RE
+            .captures(line)
+            .ok_or_else(|| ParseError::at(input, line, "expected a link like \"a-b\""))?;
         adjacencies
             .entry(cap[1].to_string())
             .or_insert_with(Vec::new)
@@ -59,8 +67,11 @@ pub fn gen(input: &str) -> CaveSystem {
             .or_insert_with(Vec::new)
             .push(cap[1].to_string());
     }
+    if !adjacencies.contains_key("start") {
+        return Err(ParseError::end(input, "no link leaves the start cave"));
+    }
 
-    CaveSystem { adjacencies }
+    Ok(CaveSystem { adjacencies })
 }
 
 pub fn first(cave_system: &CaveSystem) -> u64 {
@@ -71,65 +82,56 @@ pub fn second(cave_system: &CaveSystem) -> u64 {
     cave_system.paths("start", Vec::new(), &mut 0, false, false)
 }
 
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = CaveSystem;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::examples::example;
+    use crate::Part;
 
     #[test]
     fn one() {
-        let sample = gen("start-A
-start-b
-A-c
-A-b
-b-d
-A-end
-b-end");
+        let sample = gen(example(2021, 12, Part::One, "10")).unwrap();
         assert_eq!(first(&sample), 10);
     }
 
     #[test]
     fn two() {
-        let sample = gen("start-A
-start-b
-A-c
-A-b
-b-d
-A-end
-b-end");
+        let sample = gen(example(2021, 12, Part::Two, "36")).unwrap();
         assert_eq!(second(&sample), 36);
     }
 
     #[test]
     fn more() {
-        let i1 = gen("dc-end
-HN-start
-start-kj
-dc-start
-dc-HN
-LN-dc
-HN-end
-kj-sa
-kj-HN
-kj-dc");
-        let i2 = gen("fs-end
-he-DX
-fs-he
-start-DX
-pj-DX
-end-zg
-zg-sl
-zg-pj
-pj-he
-RW-he
-fs-DX
-pj-RW
-zg-RW
-start-pj
-he-WI
-zg-he
-pj-fs
-start-RW");
+        let i1 = gen(example(2021, 12, Part::Two, "103")).unwrap();
+        let i2 = gen(example(2021, 12, Part::Two, "3509")).unwrap();
         assert_eq!(second(&i1), 103);
         assert_eq!(second(&i2), 3509);
     }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("start-A\nA end").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = gen("A-end").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+    }
 }
@@ -0,0 +1,201 @@
+use crate::ocr::{self, UnknownGlyph};
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::{Answer, PartError, PartResult};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashSet;
+
+static RE_FOLD: Lazy<Regex> = Lazy::new(|| Regex::new(r"^fold along ([xy])=(\d+)$").unwrap());
+
+#[derive(Debug)]
+pub struct FoldInfo {
+    points: HashSet<(usize, usize)>,
+    folds: Vec<(char, usize)>,
+}
+
+impl FoldInfo {
+    fn perform_folds(&self) -> HashSet<(usize, usize)> {
+        self.folds
+            .iter()
+            .fold(self.points.clone(), |paper, &(d, at)| match d {
+                'x' => fold_x(paper, at),
+                'y' => fold_y(paper, at),
+                z => panic!("Unexpected input {}", z),
+            })
+    }
+}
+
+pub fn gen(input: &str) -> ParseResult<FoldInfo> {
+    let mut points = HashSet::new();
+    let mut folds: Vec<(char, usize)> = Vec::new();
+    let mut fold_at = Vec::new();
+
+    for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if let Some((x, y)) = line.split_once(',') {
+            points.insert((parse::number(input, x)?, parse::number(input, y)?));
+        } else {
+            let cap = RE_FOLD.captures(line).ok_or_else(|| {
+                ParseError::at(input, line, "expected a point or a \"fold along\" line")
+            })?;
+            let at = cap.get(2).unwrap().as_str();
+            folds.push((cap[1].chars().next().unwrap(), parse::number(input, at)?));
+            fold_at.push(at);
+        }
+    }
+    if points.is_empty() {
+        return Err(ParseError::end(input, "expected at least one point"));
+    }
+    if folds.is_empty() {
+        return Err(ParseError::end(input, "expected at least one fold"));
+    }
+    // Both axes fold independently, so following the coordinates the dots take on each is
+    // enough to check that no fold moves a dot past the top or left edge
+    let mut xs: HashSet<usize> = points.iter().map(|p| p.0).collect();
+    let mut ys: HashSet<usize> = points.iter().map(|p| p.1).collect();
+    for (&(d, at), text) in folds.iter().zip(fold_at) {
+        let coords = if d == 'x' { &mut xs } else { &mut ys };
+        let limit = at.saturating_mul(2);
+        if coords.iter().any(|&c| c > limit) {
+            let message = format!("folding would move dots past {}=0", d);
+            return Err(ParseError::at(input, text, message));
+        }
+        *coords = coords.iter().map(|&c| c.min(limit - c)).collect();
+    }
+    Ok(FoldInfo { points, folds })
+}
+
+/// Draws the dots as `#` on a background of `.`, starting from the top left corner of the paper.
+fn render(points: &HashSet<(usize, usize)>) -> Vec<String> {
+    let max_x = points.iter().map(|p| p.0).max().unwrap_or(0);
+    let max_y = points.iter().map(|p| p.1).max().unwrap_or(0);
+
+    (0..=max_y)
+        .map(|y| {
+            (0..=max_x)
+                .map(|x| if points.contains(&(x, y)) { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
+
+fn fold_y(points: HashSet<(usize, usize)>, y_fold: usize) -> HashSet<(usize, usize)> {
+    points
+        .iter()
+        .map(|&(x, y)| {
+            if y < y_fold {
+                (x, y)
+            } else {
+                (x, y - 2 * (y - y_fold))
+            }
+        })
+        .collect()
+}
+
+fn fold_x(points: HashSet<(usize, usize)>, x_fold: usize) -> HashSet<(usize, usize)> {
+    points
+        .iter()
+        .map(|&(x, y)| {
+            if x < x_fold {
+                (x, y)
+            } else {
+                (x - 2 * (x - x_fold), y)
+            }
+        })
+        .collect()
+}
+
+pub fn first(fold_info: &FoldInfo) -> usize {
+    let (d, num) = fold_info.folds[0];
+    if d == 'x' {
+        fold_x(fold_info.points.clone(), num).len()
+    } else if d == 'y' {
+        fold_y(fold_info.points.clone(), num).len()
+    } else {
+        panic!("Unexpected input {}", d)
+    }
+}
+
+pub fn second(fold_info: &FoldInfo) -> Result<String, UnknownGlyph> {
+    ocr::read(&fold_info.perform_folds())
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = FoldInfo;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        // Letters that cannot be read are left for a human to decipher, with the glyph that
+        // stopped the reading so that it can be added to the known ones
+        second(input).map(Answer::Str).map_err(|e| {
+            let picture = render(&input.perform_folds()).join("\n");
+            PartError::Failed(format!("{}\npaper:\n{}", e, picture))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::examples::example;
+    use crate::Part;
+
+    fn sample() -> FoldInfo {
+        gen(example(2021, 13, Part::One, "17")).unwrap()
+    }
+
+    #[test]
+    fn one() {
+        assert_eq!(first(&sample()), 17);
+    }
+
+    #[test]
+    fn two() {
+        let square = ["#####", "#...#", "#...#", "#...#", "#####"];
+        assert_eq!(render(&sample().perform_folds()), square);
+        assert_eq!(second(&sample()).unwrap_err().position, 0);
+        let message = Day13::second(&sample()).unwrap_err().to_string();
+        assert!(message.starts_with("unknown glyph for letter 1:"));
+        assert!(message.ends_with(&format!("paper:\n{}", square.join("\n"))));
+    }
+
+    #[test]
+    fn letters() {
+        // "HI" drawn upside down, unfolded by folding along y=6
+        let input = gen(
+            "0,7\n0,8\n0,9\n0,10\n0,11\n0,12\n1,10\n2,10\n3,7\n3,8\n3,9\n3,10\n3,11\n3,12
+6,7\n7,7\n8,7\n7,8\n7,9\n7,10\n7,11\n6,12\n7,12\n8,12
+
+fold along y=6",
+        )
+        .unwrap();
+        assert_eq!(second(&input), Ok("HI".to_string()));
+        assert_eq!(Day13::second(&input), Ok(Answer::from("HI")));
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("6,10\n0,14\n\nfold along z=7").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+        let err = gen("6,10\n0,-14\n\nfold along y=7").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = gen("6,10\n0,14\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = gen("0,20\n\nfold along y=5").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 14));
+        // Folds apply one after the other, the dot at x=9 landing on x=1 after the first one
+        let err = gen("4,0\n9,0\n\nfold along x=5\nfold along x=1").unwrap_err();
+        assert_eq!((err.line, err.column), (5, 14));
+        assert!(gen("9,0\n\nfold along x=5\nfold along x=1").is_ok());
+    }
+}
@@ -0,0 +1,131 @@
+use crate::parse::{ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+
+static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\w)(\w) -> (\w)$").unwrap());
+
+#[derive(Debug)]
+pub struct RuleSet {
+    orig: String,
+    rules: HashMap<(char, char), char>,
+}
+
+pub fn gen(input: &str) -> ParseResult<RuleSet> {
+    let mut lines = input.lines().map(str::trim).filter(|l| !l.is_empty());
+    let orig = lines
+        .next()
+        .ok_or_else(|| ParseError::end(input, "expected a polymer template"))?;
+    if let Some(at) = orig.matches(|c: char| !c.is_alphabetic()).next() {
+        return Err(ParseError::at(
+            input,
+            at,
+            "expected a letter in the template",
+        ));
+    }
+    let mut rules = HashMap::new();
+
+    for line in lines {
+        let cap = RE
+            .captures(line)
+            .ok_or_else(|| ParseError::at(input, line, "expected a rule like \"AB -> C\""))?;
+        let c = |i: usize| cap[i].chars().next().unwrap();
+        rules.insert((c(1), c(2)), c(3));
+    }
+
+    Ok(RuleSet {
+        orig: orig.to_string(),
+        rules,
+    })
+}
+
+fn compute(rule_set: &RuleSet, max_it: u8) -> u128 {
+    let mut polys: HashMap<(char, char), u128> = rule_set
+        .orig
+        .chars()
+        .zip(rule_set.orig.chars().skip(1))
+        .fold(HashMap::new(), |mut acc, pair| {
+            *acc.entry(pair).or_insert(0) += 1;
+            acc
+        });
+    for _ in 0..max_it {
+        let mut will_push = Vec::new();
+        for ((c1, c2), n) in polys.iter_mut() {
+            if *n == 0 {
+                continue;
+            }
+            if let Some(&new) = rule_set.rules.get(&(*c1, *c2)) {
+                will_push.push(((*c1, new), *n));
+                will_push.push(((new, *c2), *n));
+                *n = 0;
+            }
+        }
+        for ((a1, a2), n) in will_push {
+            *polys.entry((a1, a2)).or_insert(0) += n;
+        }
+    }
+    let mut count = HashMap::new();
+    for ((c1, _), n) in polys.iter() {
+        *count.entry(c1).or_insert(0) += n;
+    }
+    let last_char = rule_set.orig.chars().last().unwrap();
+    *count.entry(&last_char).or_insert(0) += 1;
+
+    count.values().max().unwrap() - count.values().min().unwrap()
+}
+
+pub fn first(rule_set: &RuleSet) -> u128 {
+    compute(rule_set, 10)
+}
+pub fn second(rule_set: &RuleSet) -> u128 {
+    compute(rule_set, 40)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = RuleSet;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::examples::example;
+    use crate::Part;
+    fn input() -> RuleSet {
+        gen(example(2021, 14, Part::One, "1588")).unwrap()
+    }
+
+    #[test]
+    fn one() {
+        assert_eq!(first(&input()), 1588);
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(second(&input()), 2188189693529);
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("NNCB\n\nCH -> B\nHH => N").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+        let err = gen("NN-B\n\nCH -> B").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+    }
+}
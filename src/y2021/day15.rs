@@ -1,4 +1,7 @@
-use crate::matrix::Matrix;
+use crate::common::matrix::Matrix;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use crate::PartResult;
 use std::collections::{BinaryHeap, HashMap};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -18,7 +21,7 @@ impl Point {
 
 impl PartialOrd for Point {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        other.weight.partial_cmp(&self.weight)
+        Some(self.cmp(other))
     }
 }
 
@@ -31,22 +34,16 @@ impl Ord for Point {
     }
 }
 
-pub fn gen(input: &str) -> Matrix<u32> {
-    let items = input.chars().filter_map(|c| c.to_digit(10));
-    let width = input.chars().position(|c| c == '\n').unwrap();
-    let height = input.lines().count();
-
-    Matrix::new(items, width, height)
+pub fn gen(input: &str) -> ParseResult<Matrix<u32>> {
+    Matrix::parse_grid(input, |c| c.to_digit(10).ok_or("expected a digit"))
 }
 
 pub fn first(field: &Matrix<u32>) -> u32 {
     let mut q = BinaryHeap::new();
     let mut distances = HashMap::new();
 
-    for x in 0..field.width() {
-        for y in 0..field.height() {
-            distances.insert((x, y), u32::MAX);
-        }
+    for position in field.iter_coords() {
+        distances.insert(position, u32::MAX);
     }
     q.push(Point::new(0, 0, 0));
     *distances.get_mut(&(0, 0)).unwrap() = 0;
@@ -62,7 +59,7 @@ pub fn first(field: &Matrix<u32>) -> u32 {
             continue;
         }
         for (xn, yn) in field.rook_neighbor_indices(x, y) {
-            let alt = weight.checked_add(field[(xn, yn)]).unwrap_or(u32::MAX);
+            let alt = weight.saturating_add(field[(xn, yn)]);
             let curr_distance = distances[&(xn, yn)];
 
             if alt < curr_distance {
@@ -74,47 +71,53 @@ pub fn first(field: &Matrix<u32>) -> u32 {
     unreachable!()
 }
 
-pub fn gen2(input: &str) -> Matrix<u32> {
-    let small = gen(input);
-    let mut result = Matrix::new(
-        vec![0; 25 * small.len()],
-        small.width() * 5,
-        small.height() * 5,
-    );
-
-    for x in 0..result.width() {
-        for y in 0..result.height() {
-            let add_risk = ((x / small.width()) + (y / small.height())) as u32;
-            let rel_x = x % small.width();
-            let rel_y = y % small.height();
-            let val = (add_risk + small[(rel_x, rel_y)]) % 9;
-
-            result[(x, y)] = if val == 0 { 9 } else { val };
-        }
-    }
+pub fn gen2(input: &str) -> ParseResult<Matrix<u32>> {
+    Ok(expand(&gen(input)?))
+}
 
-    result
+pub fn expand(small: &Matrix<u32>) -> Matrix<u32> {
+    // Each tile away from the top left one adds 1 to the risks, wrapping from 9 back to 1
+    small.tile(5, 5, |(x, y), &risk| {
+        let val = (risk + (x + y) as u32) % 9;
+        if val == 0 {
+            9
+        } else {
+            val
+        }
+    })
 }
 
 pub fn second(field: &Matrix<u32>) -> u32 {
     first(field)
 }
 
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Matrix<u32>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(&expand(input)).into())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::examples::example;
+    use crate::Part;
 
     fn sample() -> Matrix<u32> {
-        gen("1163751742
-1381373672
-2136511328
-3694931569
-7463417111
-1319128137
-1359912421
-3125421639
-1293138521
-2311944581")
+        gen(example(2021, 15, Part::One, "40")).unwrap()
     }
 
     #[test]
@@ -125,7 +128,8 @@ mod test {
     #[test]
     fn mini1() {
         let input = gen("11
-                         91");
+                         91")
+        .unwrap();
         assert_eq!(first(&input), 2);
     }
 
@@ -136,7 +140,16 @@ mod test {
                          111991
                          111111
                          111199
-                         111111");
+                         111111")
+        .unwrap();
         assert_eq!(first(&input), 14);
     }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("116\n13a\n213").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "a"));
+        let err = gen("116\n13\n213").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
 }
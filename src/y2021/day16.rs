@@ -0,0 +1,250 @@
+use crate::common::bits::as_number;
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+
+use bitvec::prelude::*;
+
+pub fn hex_to_bin(input: &str) -> ParseResult<BitVec> {
+    let mut bits = BitVec::new();
+    for (ch, at) in parse::chars(input.trim()) {
+        let nibble = match ch {
+            '0' => bitvec![0, 0, 0, 0],
+            '1' => bitvec![0, 0, 0, 1],
+            '2' => bitvec![0, 0, 1, 0],
+            '3' => bitvec![0, 0, 1, 1],
+            '4' => bitvec![0, 1, 0, 0],
+            '5' => bitvec![0, 1, 0, 1],
+            '6' => bitvec![0, 1, 1, 0],
+            '7' => bitvec![0, 1, 1, 1],
+            '8' => bitvec![1, 0, 0, 0],
+            '9' => bitvec![1, 0, 0, 1],
+            'A' => bitvec![1, 0, 1, 0],
+            'B' => bitvec![1, 0, 1, 1],
+            'C' => bitvec![1, 1, 0, 0],
+            'D' => bitvec![1, 1, 0, 1],
+            'E' => bitvec![1, 1, 1, 0],
+            'F' => bitvec![1, 1, 1, 1],
+            _ => return Err(ParseError::at(input, at, "expected a hexadecimal digit")),
+        };
+        bits.extend(nibble);
+    }
+    Ok(bits)
+}
+
+/// Nesting beyond this is rejected rather than risking the stack.
+const MAX_DEPTH: usize = 256;
+
+/// A decoded packet with its sub-packets.
+#[derive(Debug)]
+pub struct Packet {
+    version: u32,
+    type_id: u32,
+    sub_packets: Vec<Packet>,
+    value: Option<u64>,
+}
+
+/// Reads packets off the bits of a transmission, placing errors at the digit holding the
+/// offending bit.
+struct Reader<'a> {
+    input: &'a str,
+    digits: &'a str,
+    bits: &'a BitSlice,
+    head: usize,
+}
+
+impl Reader<'_> {
+    fn error_at(&self, bit: usize, message: &str) -> ParseError {
+        let digit = bit / 4;
+        ParseError::at(self.input, &self.digits[digit..digit + 1], message)
+    }
+
+    fn read(&mut self, width: usize) -> ParseResult<u64> {
+        let end = self.head + width;
+        if end > self.bits.len() {
+            let end = &self.digits[self.digits.len()..];
+            let message = "the transmission ends in the middle of a packet";
+            return Err(ParseError::at(self.input, end, message));
+        }
+        let number = as_number(self.bits[self.head..end].iter());
+        self.head = end;
+        Ok(number)
+    }
+
+    fn packet(&mut self, depth: usize) -> ParseResult<Packet> {
+        let start = self.head;
+        if depth > MAX_DEPTH {
+            return Err(self.error_at(start, "packets are nested too deeply"));
+        }
+        let version = self.read(3)? as u32;
+        let type_id = self.read(3)? as u32;
+
+        if type_id == 4 {
+            let mut value = 0;
+            for group in 0.. {
+                if group == 16 {
+                    return Err(self.error_at(self.head, "literal value does not fit in 64 bits"));
+                }
+                let more = self.read(1)? == 1;
+                value = value << 4 | self.read(4)?;
+                if !more {
+                    break;
+                }
+            }
+            return Ok(Packet {
+                version,
+                type_id,
+                sub_packets: vec![],
+                value: Some(value),
+            });
+        }
+
+        let mut sub_packets = Vec::new();
+        // Depending on length type ID
+        if self.read(1)? == 0 {
+            let length = self.read(15)? as usize;
+            let end = self.head + length;
+            while self.head < end {
+                sub_packets.push(self.packet(depth + 1)?);
+            }
+            if self.head > end {
+                return Err(self.error_at(end, "sub-packet runs past the length of its operator"));
+            }
+        } else {
+            let num_packets = self.read(11)? as usize;
+            while sub_packets.len() < num_packets {
+                sub_packets.push(self.packet(depth + 1)?);
+            }
+        }
+        match (type_id, sub_packets.len()) {
+            (2 | 3, 0) => Err(self.error_at(start, "minimum and maximum need a sub-packet")),
+            (5..=7, count) if count != 2 => {
+                Err(self.error_at(start, "comparisons need exactly two sub-packets"))
+            }
+            _ => Ok(Packet {
+                version,
+                type_id,
+                sub_packets,
+                value: None,
+            }),
+        }
+    }
+}
+
+impl Packet {
+    fn sum(&self) -> u128 {
+        self.version as u128 + self.sub_packets.iter().map(|p| p.sum()).sum::<u128>()
+    }
+
+    fn evaluate(&self) -> u128 {
+        let mut sub_values = self.sub_packets.iter().map(|p| p.evaluate());
+        match self.type_id {
+            0 => sub_values.sum(),
+            1 => sub_values.product(),
+            2 => sub_values.min().unwrap(),
+            3 => sub_values.max().unwrap(),
+            4 => self.value.unwrap() as u128,
+            5 => (sub_values.next().unwrap() > sub_values.next().unwrap()) as u128,
+            6 => (sub_values.next().unwrap() < sub_values.next().unwrap()) as u128,
+            7 => (sub_values.next().unwrap() == sub_values.next().unwrap()) as u128,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Decodes the outermost packet of a transmission, only zero padding may follow it.
+pub fn gen(input: &str) -> ParseResult<Packet> {
+    let digits = input.trim();
+    if digits.is_empty() {
+        return Err(ParseError::end(
+            input,
+            "expected a hexadecimal transmission",
+        ));
+    }
+    let bits = hex_to_bin(input)?;
+    let mut reader = Reader {
+        input,
+        digits,
+        bits: &bits,
+        head: 0,
+    };
+    let packet = reader.packet(0)?;
+    if let Some(extra) = bits[reader.head..].first_one() {
+        return Err(reader.error_at(
+            reader.head + extra,
+            "unexpected data after the outermost packet",
+        ));
+    }
+    Ok(packet)
+}
+
+pub fn first(input: &Packet) -> u128 {
+    input.sum()
+}
+
+pub fn second(input: &Packet) -> u128 {
+    input.evaluate()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Packet;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("8A004A801A8002F478" => 16; "Sample 1")]
+    #[test_case("620080001611562C8802118E34" => 12; "Sample 2")]
+    #[test_case("C0015000016115A2E0802F182340" => 23; "Sample 3")]
+    #[test_case("A0016C880162017C3686B18A3D4780" => 31; "Sample 4")]
+    fn one(input: &str) -> u128 {
+        first(&gen(input).unwrap())
+    }
+
+    #[test_case("C200B40A82" => 3; "Sample 1")]
+    #[test_case("04005AC33890" => 54; "Sample 2")]
+    #[test_case("880086C3E88112" => 7; "Sample 3")]
+    #[test_case("CE00C43D881120" => 9; "Sample 4")]
+    #[test_case("D8005AC2A8F0" => 1; "Sample 5")]
+    #[test_case("F600BC2D8F" => 0; "Sample 6")]
+    #[test_case("9C005AC2F8F0" => 0; "Sample 7")]
+    #[test_case("9C0141080250320F1802104A08" => 1; "Sample 8")]
+    fn two(input: &str) -> u128 {
+        second(&gen(input).unwrap())
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = hex_to_bin("C200x40A82").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 5, "x"));
+    }
+
+    #[test_case("" => (1, 1); "empty")]
+    #[test_case("1" => (1, 2); "truncated header")]
+    #[test_case("00100" => (1, 6); "truncated operator")]
+    #[test_case("D2FE28FF" => (1, 7); "trailing data")]
+    #[test_case("0A000" => (1, 1); "minimum without sub-packets")]
+    #[test_case("16004408" => (1, 1); "comparison with one sub-packet")]
+    #[test_case("000028408" => (1, 9); "sub-packet past its operator")]
+    fn bad_packets(input: &str) -> (usize, usize) {
+        let err = gen(input).unwrap_err();
+        (err.line, err.column)
+    }
+}
@@ -0,0 +1,155 @@
+use crate::cancel;
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+use once_cell::sync::{Lazy, OnceCell};
+use regex::Regex;
+
+static RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^target area: x=(-?\d+)\.\.(-?\d+), y=(-?\d+)\.\.(-?\d+)$").unwrap());
+
+#[derive(Debug, Clone)]
+pub struct Target {
+    x_min: i32,
+    x_max: i32,
+    y_min: i32,
+    y_max: i32,
+    /// Both parts look at the same throws, so they are only simulated once per target
+    has_worked: OnceCell<Vec<(i32, i32, i32)>>,
+}
+
+impl Target {
+    fn has_worked(&self) -> &[(i32, i32, i32)] {
+        self.has_worked.get_or_init(|| compute(self))
+    }
+}
+
+pub fn gen(input: &str) -> ParseResult<Target> {
+    let line = input.trim();
+    let cap = RE
+        .captures(line)
+        .ok_or_else(|| ParseError::at(input, line, "expected \"target area: x=A..B, y=C..D\""))?;
+    // Within these bounds a throw straight at a corner hits on the first step
+    let number = |i| {
+        let at = cap.get(i).unwrap().as_str();
+        match parse::number(input, at)? {
+            n if (-999..=999).contains(&n) => Ok(n),
+            _ => Err(ParseError::at(
+                input,
+                at,
+                "expected a number from -999 to 999",
+            )),
+        }
+    };
+    let range = |i: usize| {
+        let (min, max) = (number(i)?, number(i + 1)?);
+        if min > max {
+            let at = &line[cap.get(i).unwrap().start()..cap.get(i + 1).unwrap().end()];
+            return Err(ParseError::at(input, at, "range ends before it starts"));
+        }
+        Ok((min, max))
+    };
+    let (x_min, x_max) = range(1)?;
+    let (y_min, y_max) = range(3)?;
+    Ok(Target {
+        x_min,
+        x_max,
+        y_min,
+        y_max,
+        has_worked: OnceCell::new(),
+    })
+}
+
+fn compute(target: &Target) -> Vec<(i32, i32, i32)> {
+    let &Target {
+        x_min,
+        x_max,
+        y_min,
+        y_max,
+        ..
+    } = target;
+    let mut has_worked = Vec::new();
+
+    for v_x_0 in -1000..1000 {
+        cancel::checkpoint();
+        'throw: for v_y_0 in -1000..1000 {
+            let mut x: i32 = 0;
+            let mut y: i32 = 0;
+            let mut v_x = v_x_0;
+            let mut v_y = v_y_0;
+            let mut max_height = v_y;
+            while x.abs() < 10000 && y.abs() < 10000 {
+                x += v_x;
+                y += v_y;
+                if y > max_height {
+                    max_height = y;
+                }
+                if x_min <= x && x <= x_max && y_min <= y && y <= y_max {
+                    has_worked.push((max_height, v_x_0, v_y_0));
+                    continue 'throw;
+                }
+                v_x -= v_x.signum();
+                v_y -= 1;
+            }
+        }
+    }
+    has_worked
+}
+
+pub fn first(input: &Target) -> i32 {
+    input.has_worked().iter().map(|z| z.0).max().unwrap()
+}
+
+pub fn second(input: &Target) -> i32 {
+    input.has_worked().len() as i32
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Target;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::examples::example;
+    use crate::Part;
+
+    #[test]
+    fn one() {
+        let input = gen(example(2021, 17, Part::One, "45")).unwrap();
+        assert_eq!(first(&input), 45);
+    }
+
+    #[test]
+    fn two() {
+        let input = gen(example(2021, 17, Part::Two, "112")).unwrap();
+        assert_eq!(second(&input), 112);
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("target area: x=20..30, y=-10..-5x").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = gen("target area: x=20..30, y=-10..-99999999999").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 31));
+        let err = gen("target area: x=1..0, y=0..0").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 16, "1..0"));
+        let err = gen("target area: x=20..30, y=-1000..-5").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 26));
+    }
+}
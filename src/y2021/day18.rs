@@ -1,5 +1,8 @@
-use anyhow::Context;
+use crate::parse::{ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
 use itertools::Itertools;
+use std::fmt;
 use std::iter::Sum;
 use std::ops::{Add, Index, IndexMut};
 use std::str::FromStr;
@@ -30,18 +33,25 @@ impl PartialEq for Elem {
     }
 }
 
-impl ToString for Elem {
-    fn to_string(&self) -> String {
+impl fmt::Display for Elem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Elem::Num(n) => n.to_string(),
-            Elem::Com(b) => format!("[{},{}]", b.0.to_string(), b.1.to_string()),
+            Elem::Num(n) => write!(f, "{}", n),
+            Elem::Com(b) => write!(f, "[{},{}]", b.0, b.1),
         }
     }
 }
 
 impl FromStr for Elem {
-    type Err = anyhow::Error;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_in(s, s)
+    }
+}
+
+impl Elem {
+    /// Parses `s`, a slice of `input`, so that errors point into `input`.
+    fn parse_in(input: &str, s: &str) -> ParseResult<Self> {
         if let Some(ss) = s.strip_prefix('[') {
             let mut rel_depth = 0;
             let comma_pos = ss
@@ -55,7 +65,7 @@ impl FromStr for Elem {
                     };
                     false
                 })
-                .context("Failed to find correspoding comma")?;
+                .ok_or_else(|| ParseError::at(input, s, "pair without a comma"))?;
             let mut rel_depth = 0;
             let closing_pos = ss
                 .chars()
@@ -68,16 +78,20 @@ impl FromStr for Elem {
                     };
                     false
                 })
-                .context("Failed to find correspoding closing comma")?;
+                .ok_or_else(|| ParseError::at(input, s, "pair without a closing bracket"))?;
+            let rest = &ss[closing_pos + 1..];
+            if !rest.is_empty() && rest != "," {
+                return Err(ParseError::at(input, rest, "unexpected text after a pair"));
+            }
             let (subs1, subs2) = ss[..closing_pos].split_at(comma_pos + 1);
-            let elem1 = Self::from_str(subs1)?;
-            let elem2 = Self::from_str(subs2)?;
+            let elem1 = Self::parse_in(input, subs1)?;
+            let elem2 = Self::parse_in(input, subs2)?;
             return Ok(Self::Com(Box::new((elem1, elem2))));
         }
-        if let Ok(n) = s.trim_end_matches(',').parse() {
-            return Ok(Self::Num(n));
-        }
-        Err(anyhow::anyhow!("problem with string \"{}\"", s))
+        let num = s.trim_end_matches(',');
+        num.parse()
+            .map(Self::Num)
+            .map_err(|_| ParseError::at(input, num, "expected a number or a pair"))
     }
 }
 
@@ -280,16 +294,24 @@ impl Elem {
     }
 }
 
-pub fn first(input: &str) -> u128 {
-    input
+pub fn gen(input: &str) -> ParseResult<Vec<Elem>> {
+    let elems = input
         .lines()
-        .map(|l| Elem::from_str(l).unwrap())
-        .sum::<Elem>()
-        .magnitude()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|l| Elem::parse_in(input, l))
+        .collect::<ParseResult<Vec<_>>>()?;
+    if elems.is_empty() {
+        return Err(ParseError::end(input, "expected at least one number"));
+    }
+    Ok(elems)
+}
+
+pub fn first(elems: &[Elem]) -> u128 {
+    elems.iter().cloned().sum::<Elem>().magnitude()
 }
 
-pub fn second(input: &str) -> u128 {
-    let elems: Vec<_> = input.lines().map(|l| Elem::from_str(l).unwrap()).collect();
+pub fn second(elems: &[Elem]) -> u128 {
     elems
         .iter()
         .cartesian_product(elems.iter())
@@ -298,9 +320,30 @@ pub fn second(input: &str) -> u128 {
         .unwrap()
 }
 
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = Vec<Elem>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::examples::example;
+    use crate::Part;
     use test_case::test_case;
 
     #[test]
@@ -418,32 +461,24 @@ mod test {
 [1,[[[9,3],9],[[9,0],[0,7]]]]
 [[[5,[7,4]],7],1]
 [[[[4,2],2],6],[8,7]]", "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"; "sample 1")]
-    #[test_case("[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-[[[5,[2,8]],4],[5,[[9,9],0]]]
-[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-[[[[5,4],[7,7]],8],[[8,3],8]]
-[[9,3],[[9,9],[6,[4,9]]]]
-[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]", "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"; "sample 2")]
+    #[test_case(example(2021, 18, Part::One, "4140"), "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"; "sample 2")]
     fn sum(input: &str, expected: &str) {
         let sum: Elem = input.lines().map(|l| Elem::from_str(l).unwrap()).sum();
         assert_eq!(sum, Elem::from_str(expected).unwrap());
     }
 
-    #[test_case("[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-[[[5,[2,8]],4],[5,[[9,9],0]]]
-[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-[[[[5,4],[7,7]],8],[[8,3],8]]
-[[9,3],[[9,9],[6,[4,9]]]]
-[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]", "3993"; "sample 1")]
+    #[test_case(example(2021, 18, Part::Two, "3993"), "3993"; "sample 1")]
     fn part2(input: &str, output: &str) {
-        assert_eq!(second(input), output.parse().unwrap());
+        assert_eq!(second(&gen(input).unwrap()), output.parse().unwrap());
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("[1,2]\n[[3,x],4]").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "x"));
+        let err = gen("[1,2]\n[[3,4],5]]").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 10));
+        let err = gen("[1,2]\n[[3,4],5").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
     }
 }
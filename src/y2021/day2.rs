@@ -0,0 +1,101 @@
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Forward,
+    Down,
+    Up,
+}
+
+pub fn gen(input: &str) -> ParseResult<Vec<(Direction, i32)>> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (direction, n) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(input, line, "expected a direction and a number"))?;
+            let direction = match direction {
+                "forward" => Direction::Forward,
+                "down" => Direction::Down,
+                "up" => Direction::Up,
+                _ => return Err(ParseError::at(input, direction, "unknown direction")),
+            };
+            Ok((direction, parse::number(input, n)?))
+        })
+        .collect()
+}
+
+pub fn first(input: &[(Direction, i32)]) -> i32 {
+    let mut forward = 0;
+    let mut depth = 0;
+    input.iter().for_each(|&(direction, n)| match direction {
+        Direction::Forward => forward += n,
+        Direction::Down => depth += n,
+        Direction::Up => depth -= n,
+    });
+    forward * depth
+}
+
+pub fn second(input: &[(Direction, i32)]) -> i32 {
+    let mut horizontal = 0;
+    let mut depth = 0;
+    let mut aim = 0;
+
+    input.iter().for_each(|&(direction, n)| match direction {
+        Direction::Forward => {
+            horizontal += n;
+            depth += aim * n;
+        }
+        Direction::Down => aim += n,
+        Direction::Up => aim -= n,
+    });
+    horizontal * depth
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<(Direction, i32)>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::example;
+    use crate::Part;
+    #[test]
+    fn test_1() {
+        let input = example(2021, 2, Part::One, "150");
+        assert_eq!(super::first(&super::gen(input).unwrap()), 150);
+    }
+
+    #[test]
+    fn test_2() {
+        let input = example(2021, 2, Part::Two, "900");
+        assert_eq!(super::second(&super::gen(input).unwrap()), 900);
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = super::gen("forward 5\nsideways 2").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = super::gen("forward 5\ndown two").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::common::bits::as_number;
+use crate::common::matrix::{Matrix, Topology};
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+use itertools::Itertools;
+
+#[allow(dead_code)]
+fn print_mat(mat: &Matrix<bool>) {
+    for row in mat.rows() {
+        for pixel in row {
+            match pixel {
+                true => print!("#"),
+                false => print!("."),
+            };
+        }
+        println!();
+    }
+}
+
+#[derive(Debug)]
+pub struct Image {
+    code: Vec<bool>,
+    image: Matrix<bool>,
+}
+
+fn pixel(c: char) -> Result<bool, &'static str> {
+    match c {
+        '.' => Ok(false),
+        '#' => Ok(true),
+        _ => Err("expected '.' or '#'"),
+    }
+}
+
+pub fn gen(input: &str) -> ParseResult<Image> {
+    // The algorithm ends at the first blank line, the image follows it
+    let algorithm_end = input
+        .split_inclusive('\n')
+        .scan(0, |end, line| {
+            *end += line.len();
+            Some((*end, line))
+        })
+        .find(|(_, line)| line.trim().is_empty())
+        .map_or(input.len(), |(end, _)| end);
+    let (algorithm, image) = input.split_at(algorithm_end);
+    let mut code = Vec::new();
+    for line in algorithm.lines().map(str::trim) {
+        for (ch, at) in parse::chars(line) {
+            code.push(pixel(ch).map_err(|e| ParseError::at(input, at, e))?);
+        }
+    }
+    if code.len() != 512 {
+        return Err(ParseError::end(
+            input,
+            format!(
+                "expected an enhancement algorithm of 512 pixels, got {}",
+                code.len()
+            ),
+        ));
+    }
+
+    let image = Matrix::parse_grid(image, pixel).map_err(|e| e.within(input, image))?;
+
+    Ok(Image { code, image })
+}
+
+fn compute(input: &Image, t_max: u32) -> u32 {
+    let code = &input.code;
+    let mut mat = input.image.clone();
+
+    for t in 0..t_max {
+        // print_mat(&mat);
+        let inf_point = if code[0] { t % 2 != 0 } else { false };
+        mat = mat
+            .expand_contour(3, inf_point)
+            .with_topology(Topology::Infinite(inf_point));
+        // let mut nines = Matrix::new_default(mat.width(), mat.height());
+        // Collect surrounding states
+        // for x in 0..mat.width() {
+        //     for y in 0..mat.height() {
+        //         let x = x as isize;
+        //         let y = y as isize;
+        //         let nine: Vec<_> = [y - 1, y, y + 1]
+        //             .iter()
+        //             .cartesian_product([x - 1, x, x + 1].iter())
+        //             .map(|(&xx, &yy)| *mat.get(xx, yy).unwrap_or(&inf_point))
+        //             .collect();
+        //         nines[(x as usize, y as usize)] = nine;
+        //     }
+        // }
+        // Items are collected row by row, and each nine reads its neighbourhood the same way
+        let items = mat.iter_coords().map(|(x, y)| {
+            let x = x as isize;
+            let y = y as isize;
+            let nine: [bool; 9] = [y - 1, y, y + 1]
+                .iter()
+                .cartesian_product([x - 1, x, x + 1].iter())
+                .map(|(&yy, &xx)| *mat.get(xx, yy).unwrap())
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+            nine
+        });
+        let nines = Matrix::new(items, mat.width(), mat.height());
+        // Apply code to image
+        for (position, pixel) in mat.iter_mut_with_coords() {
+            *pixel = code[as_number(nines[position].iter()) as usize];
+        }
+        // println!();
+        // println!();
+    }
+    // print_mat(&mat);
+    mat.vec.iter().filter(|b| **b).count() as u32
+}
+
+pub fn first(input: &Image) -> u32 {
+    compute(input, 2)
+}
+
+pub fn second(input: &Image) -> u32 {
+    compute(input, 50)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Image;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::example;
+    use crate::Part;
+    #[test]
+    fn part1() {
+        let input = example(2021, 20, Part::One, "35");
+        assert_eq!(first(&gen(input).unwrap()), 35)
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("#.#\n\n#..#.\n#....").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 6));
+        let code = ".".repeat(512);
+        let err = gen(&format!("{}\n\n#..#.\n#..o.", code)).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 4, "o"));
+    }
+}
@@ -0,0 +1,216 @@
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+
+static RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^Player (\d+) starting position: (\d+)$").unwrap());
+
+type Wincounts = HashMap<(Players, bool), (u128, u128)>;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Player {
+    points: u32,
+    position: u32,
+}
+
+impl Player {
+    fn handle_roll(&mut self, roll: u32) {
+        self.position = (self.position + roll) % 10;
+        self.points += match self.position {
+            0 => 10,
+            n => n,
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Players {
+    player0: Player,
+    player1: Player,
+}
+
+impl std::ops::Index<bool> for Players {
+    type Output = Player;
+    fn index(&self, index: bool) -> &Self::Output {
+        if index {
+            &self.player1
+        } else {
+            &self.player0
+        }
+    }
+}
+
+impl std::ops::IndexMut<bool> for Players {
+    fn index_mut(&mut self, index: bool) -> &mut Self::Output {
+        if index {
+            &mut self.player1
+        } else {
+            &mut self.player0
+        }
+    }
+}
+
+fn play_deter(players: &mut [Player], max_points: u32) -> (usize, usize) {
+    for t in 0.. {
+        let n = (3 * t as u32) % 100;
+        let player = &mut players[t % 2];
+        player.handle_roll(3 * n + 6);
+        if player.points >= max_points {
+            return ((t + 1) % 2, (t + 1) * 3);
+        }
+    }
+    unreachable!()
+}
+
+const DICE: [[u32; 3]; 27] = [
+    [3, 1, 1],
+    [3, 1, 2],
+    [3, 1, 3],
+    [3, 2, 1],
+    [3, 2, 2],
+    [3, 2, 3],
+    [3, 3, 1],
+    [3, 3, 2],
+    [3, 3, 3],
+    [2, 1, 1],
+    [2, 1, 2],
+    [2, 1, 3],
+    [2, 2, 1],
+    [2, 2, 2],
+    [2, 2, 3],
+    [2, 3, 1],
+    [2, 3, 2],
+    [2, 3, 3],
+    [1, 1, 1],
+    [1, 1, 2],
+    [1, 1, 3],
+    [1, 2, 1],
+    [1, 2, 2],
+    [1, 2, 3],
+    [1, 3, 1],
+    [1, 3, 2],
+    [1, 3, 3],
+];
+
+fn play_dirac(players: Players, turn: bool, memory: &mut Wincounts) -> (u128, u128) {
+    let mut win_count = (0, 0);
+    for dice in DICE {
+        let mut sub_players = players; // Copy players state
+        let roll = dice.iter().sum();
+        sub_players[turn].handle_roll(roll);
+        // Check if somebody won
+        if sub_players[turn].points >= 21 {
+            if turn {
+                win_count.1 += 1;
+            } else {
+                win_count.0 += 1;
+            }
+        } else {
+            // Dont recurse if we already know the outcome
+            let sub_win_count = if let Some(res) = memory.get(&(sub_players, turn)) {
+                *res
+            } else {
+                // Otherwise recurse with the other player rolling dice
+                let res = play_dirac(sub_players, !turn, memory);
+                memory.insert((sub_players, turn), res);
+                res
+            };
+            win_count.0 += sub_win_count.0;
+            win_count.1 += sub_win_count.1;
+        }
+    }
+    win_count
+}
+
+pub fn gen(input: &str) -> ParseResult<Players> {
+    let mut players = Vec::new();
+    for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let cap = RE.captures(line).ok_or_else(|| {
+            ParseError::at(input, line, "expected \"Player N starting position: P\"")
+        })?;
+        let (id, position) = (cap.get(1).unwrap().as_str(), cap.get(2).unwrap().as_str());
+        if parse::number::<usize>(input, id)? != players.len() + 1 {
+            return Err(ParseError::at(input, id, "players are out of order"));
+        }
+        let position = parse::number(input, position)?;
+        if !(1..=10).contains(&position) {
+            return Err(ParseError::at(input, line, "positions go from 1 to 10"));
+        }
+        players.push(Player {
+            points: 0,
+            position,
+        });
+    }
+    match players[..] {
+        [player0, player1] => Ok(Players { player0, player1 }),
+        _ => Err(ParseError::end(input, "expected exactly two players")),
+    }
+}
+
+pub fn first(input: &Players) -> usize {
+    let mut players = [input.player0, input.player1];
+    let (loser, t_max) = play_deter(&mut players, 1000);
+    players[loser].points as usize * t_max
+}
+
+pub fn second(input: &Players) -> usize {
+    let res = play_dirac(*input, false, &mut HashMap::new());
+    if res.0 > res.1 {
+        res.0.try_into().unwrap()
+    } else {
+        res.1.try_into().unwrap()
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = Players;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::example;
+    use crate::Part;
+    #[test]
+    fn part1() {
+        let input = example(2021, 21, Part::One, "739785");
+        assert_eq!(first(&gen(input).unwrap()), 739785);
+    }
+    #[test]
+    fn part2() {
+        let input = example(2021, 21, Part::Two, "444356092776315");
+        assert_eq!(second(&gen(input).unwrap()), 444356092776315);
+    }
+    #[test]
+    fn bad_input() {
+        let err = gen("Player 1 starting position: 4\nPlayer 2 starting position: 11").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = gen("Player 2 starting position: 4\nPlayer 1 starting position: 8").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 8, "2"));
+        let err = gen("Player 1 starting position: 4").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 30));
+    }
+    #[test]
+    fn second_player_wins() {
+        let input = "Player 1 starting position: 1\nPlayer 2 starting position: 3";
+        assert_eq!(first(&gen(input).unwrap()), 897798);
+    }
+}
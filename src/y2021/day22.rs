@@ -0,0 +1,162 @@
+#![allow(dead_code)]
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::{collections::HashMap, ops::RangeInclusive};
+
+static RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(on|off) x=(-?\d+)\.\.(-?\d+),y=(-?\d+)\.\.(-?\d+),z=(-?\d+)\.\.(-?\d+)$")
+        .unwrap()
+});
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    x: RangeInclusive<i64>,
+    y: RangeInclusive<i64>,
+    z: RangeInclusive<i64>,
+    on: bool,
+}
+
+impl Instruction {
+    fn contains(&self, (x, y, z): (i64, i64, i64)) -> bool {
+        self.x.contains(&x) && self.y.contains(&y) && self.z.contains(&z)
+    }
+
+    fn volume(&self) -> i64 {
+        let len = |r: &RangeInclusive<i64>| r.end() - r.start() + 1;
+        len(&self.x) * len(&self.y) * len(&self.z)
+    }
+
+    fn intersection(&self, other: &Self, on: bool) -> Option<Self> {
+        let overlap = |a: &RangeInclusive<i64>, b: &RangeInclusive<i64>| {
+            let r = *a.start().max(b.start())..=*a.end().min(b.end());
+            (!r.is_empty()).then_some(r)
+        };
+        Some(Instruction {
+            x: overlap(&self.x, &other.x)?,
+            y: overlap(&self.y, &other.y)?,
+            z: overlap(&self.z, &other.z)?,
+            on,
+        })
+    }
+}
+
+pub fn parse(input: &str) -> ParseResult<Vec<Instruction>> {
+    let mut instructions = Vec::new();
+    for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let caps = RE.captures(line).ok_or_else(|| {
+            ParseError::at(input, line, "expected \"on|off x=A..B,y=C..D,z=E..F\"")
+        })?;
+        let range = |i: usize| -> ParseResult<RangeInclusive<i64>> {
+            let (start, end) = (caps.get(i).unwrap(), caps.get(i + 1).unwrap());
+            let range = parse::number(input, start.as_str())?..=parse::number(input, end.as_str())?;
+            if range.is_empty() {
+                return Err(ParseError::at(
+                    input,
+                    end.as_str(),
+                    "range ends before it starts",
+                ));
+            }
+            Ok(range)
+        };
+        instructions.push(Instruction {
+            x: range(2)?,
+            y: range(4)?,
+            z: range(6)?,
+            on: &caps[1] == "on",
+        });
+    }
+    Ok(instructions)
+}
+
+pub fn first(instructions: &[Instruction]) -> u64 {
+    let mut state = HashMap::new();
+    'inst: for inst in instructions {
+        for x in inst.x.clone() {
+            if x.abs() > 50 {
+                continue 'inst;
+            }
+            for y in inst.y.clone() {
+                if y.abs() > 50 {
+                    continue 'inst;
+                }
+                for z in inst.z.clone() {
+                    if z.abs() > 50 {
+                        continue 'inst;
+                    }
+                    *state.entry((x, y, z)).or_insert(false) = inst.on;
+                }
+            }
+        }
+    }
+    state.values().filter(|b| **b).count() as u64
+}
+
+pub fn second(instructions: &[Instruction]) -> u64 {
+    // Every cuboid counts positively when `on` and negatively otherwise. Each new instruction
+    // cancels out what it overlaps, then adds itself if it turns cubes on.
+    let mut cuboids: Vec<Instruction> = Vec::new();
+    for inst in instructions {
+        let cancelled: Vec<_> = cuboids
+            .iter()
+            .filter_map(|cuboid| cuboid.intersection(inst, !cuboid.on))
+            .collect();
+        cuboids.extend(cancelled);
+        if inst.on {
+            cuboids.push(inst.clone());
+        }
+    }
+    cuboids
+        .iter()
+        .map(|c| if c.on { c.volume() } else { -c.volume() })
+        .sum::<i64>()
+        .try_into()
+        .unwrap()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::example;
+    use crate::Part;
+    #[test]
+    fn one() {
+        let input = example(2021, 22, Part::One, "590784");
+        assert_eq!(first(&parse(input).unwrap()), 590784);
+    }
+
+    #[test]
+    fn two() {
+        let input = example(2021, 22, Part::Two, "2758514936282235");
+        assert_eq!(second(&parse(input).unwrap()), 2758514936282235);
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = parse("on x=10..12,y=10..12,z=10..12\nof x=9..11,y=9..11,z=9..11").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("on x=10..12,y=10..8,z=10..12").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 19, "8"));
+    }
+}
@@ -1,4 +1,8 @@
-use crate::matrix::Matrix;
+use crate::cancel;
+use crate::common::matrix::Matrix;
+use crate::parse::{ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
 use std::{
     collections::{HashMap, HashSet},
     fmt,
@@ -29,42 +33,86 @@ impl fmt::Debug for Cell {
     }
 }
 
-pub fn parse(input: &str) -> Matrix<Cell> {
-    let mut items = Vec::new();
-
-    for line in input.lines() {
-        for c in line.chars() {
-            items.push(match c {
-                '#' | ' ' => Cell::Wall,
-                '.' => Cell::Free,
-                x => match x {
-                    'A' => Cell::Someone(Color::A),
-                    'B' => Cell::Someone(Color::B),
-                    'C' => Cell::Someone(Color::C),
-                    'D' => Cell::Someone(Color::D),
-                    x => panic!("Invalid input: {x}"),
-                },
+pub fn parse(input: &str) -> ParseResult<Matrix<Cell>> {
+    // Leading spaces are part of the burrow, and short rows are padded with walls
+    Matrix::parse_grid_padded(input, '#', |c| match c {
+        '#' | ' ' => Ok(Cell::Wall),
+        '.' => Ok(Cell::Free),
+        'A' => Ok(Cell::Someone(Color::A)),
+        'B' => Ok(Cell::Someone(Color::B)),
+        'C' => Ok(Cell::Someone(Color::C)),
+        'D' => Ok(Cell::Someone(Color::D)),
+        _ => Err("expected a wall, a space or A to D"),
+    })
+}
+
+/// The burrow before anyone moves, `?` standing for an amphipod.
+const BURROW: [&str; 5] = [
+    "#############",
+    "#...........#",
+    "###?#?#?#?###",
+    "###?#?#?#?###",
+    "#############",
+];
+
+/// Parses the burrow as the puzzle starts it, with an empty hallway and two amphipods of each
+/// color in the rooms.
+pub fn gen(input: &str) -> ParseResult<Matrix<Cell>> {
+    let cells = parse(input)?;
+    let mut rows = input
+        .lines()
+        .map(str::trim)
+        .skip_while(|row| row.is_empty());
+    for (y, shape) in BURROW.iter().enumerate() {
+        let row = rows
+            .next()
+            .ok_or_else(|| ParseError::end(input, "expected the rest of the burrow"))?;
+        let fits = cells.width() == shape.len()
+            && shape.chars().enumerate().all(|(x, expected)| {
+                matches!(
+                    (expected, cells[(x, y)]),
+                    ('#', Cell::Wall) | ('.', Cell::Free) | ('?', Cell::Someone(_))
+                )
             });
+        if !fits {
+            let message = format!("expected a row like \"{}\"", shape);
+            return Err(ParseError::at(input, row, message));
         }
     }
-
-    let width = input.lines().next().unwrap().len();
-    let height = input.lines().count();
-
-    while items.len() < width * height {
-        // Finish padding the last line
-        items.push(Cell::Wall);
+    if let Some(row) = rows.find(|row| !row.is_empty()) {
+        return Err(ParseError::at(input, row, "burrow is too deep"));
     }
+    for color in [Color::A, Color::B, Color::C, Color::D] {
+        let count = cells
+            .vec
+            .iter()
+            .filter(|&&cell| cell == Cell::Someone(color))
+            .count();
+        if count != 2 {
+            return Err(ParseError::end(
+                input,
+                format!(
+                    "expected two amphipods of color {:?}, found {}",
+                    color, count
+                ),
+            ));
+        }
+    }
+    Ok(cells)
+}
 
-    Matrix::new(items, width, height)
+pub fn parse_second(input: &str) -> ParseResult<Matrix<Cell>> {
+    Ok(unfold(&parse(input)?))
 }
 
-pub fn parse_second(input: &str) -> Matrix<Cell> {
-    let mut cells = parse(input);
+pub fn unfold(cells: &Matrix<Cell>) -> Matrix<Cell> {
+    let mut cells = cells.clone();
+    // Leading walls written as such, the indentation shared by both rows would be dropped
     let extension = parse(
-        "  #D#C#B#A###
-  #D#B#A#C###",
-    );
+        "###D#C#B#A###
+###D#B#A#C###",
+    )
+    .unwrap();
     cells.insert_row_at(extension, 3);
     cells
 }
@@ -190,20 +238,19 @@ fn shortest_perfection(
     if let Some(value) = memory.get(cells) {
         return *value;
     }
+    cancel::checkpoint();
 
     if is_finished(cells) {
         memory.entry(cells.clone()).or_insert(Some(0));
         return Some(0);
     }
 
-    let mut all_amphipods_pos = Vec::new();
-    for x in 0..cells.width() {
-        for y in 0..cells.height() {
-            if let Cell::Someone(who) = cells[(x, y)] {
-                all_amphipods_pos.push(((x, y), who))
-            }
-        }
-    }
+    let all_amphipods_pos = cells
+        .iter_with_coords()
+        .filter_map(|(pos, cell)| match *cell {
+            Cell::Someone(who) => Some((pos, who)),
+            _ => None,
+        });
     let mut moves_and_costs = Vec::new();
     for (pos, c) in all_amphipods_pos {
         let paths_and_lengths = possible_moves_and_length(pos, cells);
@@ -240,18 +287,33 @@ pub fn second(cells: &Matrix<Cell>) -> u32 {
     shortest_perfection(cells, &mut memory).unwrap()
 }
 
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = Matrix<Cell>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(&unfold(input)).into())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::examples::example;
+    use crate::Part;
     #[test]
     fn possible_paths_test() {
-        let cells = parse(
-            r"#############
-#...........#
-###B#C#B#D###
-  #A#D#C#A#
-  #########",
-        );
+        let cells = parse(example(2021, 23, Part::One, "12521")).unwrap();
         assert_eq!(
             possible_moves_and_length((3, 2), &cells),
             vec![
@@ -272,7 +334,8 @@ mod test {
 ###.#.#.#D###
   #A#D#B#A#
   #########",
-        );
+        )
+        .unwrap();
         assert_eq!(
             possible_moves_and_length((4, 1), &cells),
             vec![],
@@ -285,7 +348,8 @@ mod test {
 ###.#.#.#D###
   #A#D#C#A#
   #########",
-        );
+        )
+        .unwrap();
         assert_eq!(
             possible_moves_and_length((4, 1), &cells),
             vec![((7, 2), 4)],
@@ -298,7 +362,8 @@ mod test {
 ###.#.#.#D###
   #A#D#.#A#
   #########",
-        );
+        )
+        .unwrap();
         assert_eq!(
             possible_moves_and_length((4, 1), &cells),
             vec![((7, 3), 5)],
@@ -308,15 +373,29 @@ mod test {
 
     #[test]
     fn one() {
-        let cells = parse(
-            "#############
-#...........#
-###B#C#B#D###
-  #A#D#C#A#
-  #########
-",
-        );
+        let cells = parse(example(2021, 23, Part::One, "12521")).unwrap();
         let mut memory = HashMap::new();
         assert_eq!(shortest_perfection(&cells, &mut memory), Some(12521));
     }
+
+    #[test]
+    fn bad_input() {
+        let err = parse("#####\n#...#\n###E#\n  ###").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 4, "E"));
+        let err = parse("#####\n#....#").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+        let err = gen("#").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 1, "#"));
+        let err = gen("#############\n#.A.........#\n###B#C#B#D###").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = gen("#############\n#...........#\n###B#C#B#D###").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 14));
+        let err = gen("#############
+#...........#
+###B#C#B#D###
+  #A#D#C#B#
+  #########")
+        .unwrap_err();
+        assert!(err.message.contains("color A"), "{}", err.message);
+    }
 }
@@ -1,3 +1,7 @@
+use crate::cancel;
+use crate::parse::{ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::{Answer, PartError, PartResult};
 use std::collections::HashSet;
 use std::str::FromStr;
 
@@ -58,24 +62,42 @@ struct Inst {
 }
 
 impl Inst {
-    fn new(ope: &str, var: &str, token: Option<&str>) -> Self {
-        let ope = match ope {
-            "inp" => Ope::Inp,
-            "add" => Ope::Add,
-            "mul" => Ope::Mul,
-            "div" => Ope::Div,
-            "mod" => Ope::Mod,
-            "eql" => Ope::Eql,
-            other => panic!("invalid input {other}"),
+    /// Parses `line`, a slice of `input`.
+    fn new(input: &str, line: &str) -> ParseResult<Self> {
+        let mut words = line.split_whitespace();
+        let ope = match words.next() {
+            Some("inp") => Ope::Inp,
+            Some("add") => Ope::Add,
+            Some("mul") => Ope::Mul,
+            Some("div") => Ope::Div,
+            Some("mod") => Ope::Mod,
+            Some("eql") => Ope::Eql,
+            Some(other) => return Err(ParseError::at(input, other, "unknown instruction")),
+            None => return Err(ParseError::at(input, line, "expected an instruction")),
         };
-        let var = Var::from_str(var).unwrap();
-        let token = token.unwrap_or("x"); // Ugly!
-        let token = if let Ok(n) = token.parse() {
-            Token::Int(n)
-        } else {
-            Token::Var(Var::from_str(token).unwrap())
+        let var = words
+            .next()
+            .ok_or_else(|| ParseError::at(input, line, "missing variable"))?;
+        let var = Var::from_str(var)
+            .map_err(|_| ParseError::at(input, var, "expected one of w, x, y or z"))?;
+        let token = match (&ope, words.next()) {
+            // The operand of `inp` is never read
+            (Ope::Inp, None) => Token::Var(Var::X),
+            (Ope::Inp, Some(extra)) => {
+                return Err(ParseError::at(input, extra, "inp takes a single variable"))
+            }
+            (_, None) => return Err(ParseError::at(input, line, "missing operand")),
+            (_, Some(token)) => match token.parse() {
+                Ok(n) => Token::Int(n),
+                Err(_) => Token::Var(Var::from_str(token).map_err(|_| {
+                    ParseError::at(input, token, "expected a number or a variable")
+                })?),
+            },
         };
-        Inst { ope, var, token }
+        if let Some(extra) = words.next() {
+            return Err(ParseError::at(input, extra, "unexpected operand"));
+        }
+        Ok(Inst { ope, var, token })
     }
 }
 
@@ -100,7 +122,6 @@ impl Program {
     fn execute(&mut self, inputs: &Inputs, memory: &mut HashSet<(u64, State)>) -> bool {
         let mut will_memorize = Vec::new();
         for inst in &self.instr {
-            // println!("{}", self.input_idx);
             let token_value = inst.token.value_in(&self.state);
             let ope_upon = match inst.var {
                 Var::X => &mut self.state.x,
@@ -132,34 +153,28 @@ impl Program {
                 memory.insert(substate);
             }
         }
-        println!("{:?}: {:?}", inputs, self.state);
         is_valid
     }
 }
 
-pub fn gen(input: &str) -> Program {
-    let instr = input.lines().fold(Vec::new(), |mut v, line| {
-        let mut words = line.split_whitespace();
-        let ope = words.next().unwrap();
-        let var = words.next().unwrap();
-        let token = words.next();
-
-        assert!(ope != "inp" || token.is_none());
-
-        v.push(Inst::new(ope, var, token));
-        v
-    });
+pub fn gen(input: &str) -> ParseResult<Program> {
+    let instr = input
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|line| Inst::new(input, line))
+        .collect::<ParseResult<_>>()?;
     let state = State {
         x: 0,
         y: 0,
         z: 0,
         w: 0,
     };
-    Program {
+    Ok(Program {
         state,
         instr,
         input_idx: 0,
-    }
+    })
 }
 
 struct NumIter {
@@ -174,44 +189,32 @@ impl Iterator for NumIter {
         } else {
             self.i -= 1;
             while let Some(idx) = find_zero(self.i) {
-                // dbg!(idx);
                 self.i -= (self.i % 10_u64.pow(idx as u32)) + 1;
             }
-            // println!("{}", self.i);
             Some(self.i)
         }
     }
 }
 
-pub fn first(program: &Program) -> u64 {
+/// The largest model number the program accepts, if any.
+pub fn first(program: &Program) -> Option<u64> {
     let i = 99999999999999_u64;
     let mut memory = HashSet::new();
     let numbers = NumIter { i };
     for i in numbers {
+        cancel::checkpoint();
         let mut prog = program.clone();
         if prog.execute(&as_model_number(i), &mut memory) {
-            return i;
+            return Some(i);
         }
     }
-    panic!();
-    // let best = (11111111111111..=99999999999999)
-    //     .rev()
-    //     .filter(|n| !n.to_string().contains('0'))
-    //     .map(as_model_number)
-    //     // .inspect(|i| println!("{i:?}"))
-    //     .find(|mod_num| {
-    //         let mut prog = program.clone();
-    //         prog.execute(mod_num);
-    //         prog.state.z == 0
-    //     })
-    //     .unwrap();
-    // as_usual_number(best)
+    None
 }
 
 fn find_zero(num: u64) -> Option<usize> {
     (0..=13)
         .map(|exp| 10_u64.pow(exp))
-        .map(|divisor| ((num / divisor) % 10))
+        .map(|divisor| (num / divisor) % 10)
         .position(|n| n == 0)
 }
 
@@ -232,6 +235,38 @@ fn as_usual_number(num: &[i8]) -> u64 {
         })
 }
 
-pub fn second(_program: &Program) -> u64 {
-    0
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Program;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        first(input)
+            .map(Answer::from)
+            .ok_or_else(|| PartError::Failed("no model number is valid".to_string()))
+    }
+
+    fn second(_input: &Self::Input) -> PartResult {
+        Err(PartError::NotImplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_input() {
+        let err = gen("inp w\nadd x w\nsub z 3").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "sub"));
+        let err = gen("inp w\nmul x q").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 7, "q"));
+        let err = gen("inp w 3").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+    }
 }
@@ -0,0 +1,123 @@
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use crate::{PartError, PartResult};
+use std::fmt::Write;
+
+use crate::common::matrix::{Matrix, Topology};
+
+#[derive(PartialEq, Clone)]
+pub enum Cell {
+    Down,
+    Right,
+    Free,
+}
+
+impl std::fmt::Debug for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Cell::Down => 'v',
+            Cell::Right => '>',
+            Cell::Free => '.',
+        };
+        f.write_char(c)
+    }
+}
+
+pub fn gen(input: &str) -> ParseResult<Matrix<Cell>> {
+    Matrix::parse_grid(input, |c| match c {
+        '.' => Ok(Cell::Free),
+        'v' => Ok(Cell::Down),
+        '>' => Ok(Cell::Right),
+        _ => Err("expected '.', 'v' or '>'"),
+    })
+}
+
+fn apply_move(cells: &mut Matrix<Cell>, kind: Cell) -> bool {
+    let mut has_moved = false;
+
+    let mut will_swap = Vec::new();
+    for ((x, y), cell) in cells.iter_with_coords() {
+        if *cell == kind {
+            let (xi, yi) = (x as isize, y as isize);
+            let new_pos = match kind {
+                Cell::Down => cells.position(xi, yi + 1),
+                Cell::Right => cells.position(xi + 1, yi),
+                Cell::Free => continue,
+            }
+            .unwrap();
+            // println!(
+            //     "({x}, {y}) which is '{kind:?}' looking at {new_pos:?} which is '{:?}'",
+            //     cells[(new_pos)]
+            // );
+            if cells[new_pos] == Cell::Free {
+                has_moved = true;
+                will_swap.push(((x, y), new_pos));
+            }
+        }
+    }
+    for (a, b) in will_swap {
+        cells.swap(a, b);
+    }
+    has_moved
+}
+
+pub fn first(cells: &Matrix<Cell>) -> usize {
+    // Cucumbers leaving through an edge come back through the opposite one
+    let mut cells = cells.clone().with_topology(Topology::Toroidal);
+
+    let mut count = 0;
+    loop {
+        // println!("{cells:?}");
+        count += 1;
+
+        // println!("Move right-facing cucumbers...");
+        let has_moved_right = apply_move(&mut cells, Cell::Right);
+        // println!("Move down-facing cucumbers...");
+        let has_moved_down = apply_move(&mut cells, Cell::Down);
+
+        if !(has_moved_down || has_moved_right) {
+            break;
+        }
+    }
+    count
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input = Matrix<Cell>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(_input: &Self::Input) -> PartResult {
+        Err(PartError::NotImplemented)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::examples::example;
+    use crate::Part;
+
+    #[test]
+    fn one() {
+        let input = example(2021, 25, Part::One, "58");
+        assert_eq!(first(&gen(input).unwrap()), 58);
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("v...>\n.>.<.").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "<"));
+        let err = gen("v...>\n.>..").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
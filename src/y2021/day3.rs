@@ -0,0 +1,148 @@
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+use std::collections::HashSet;
+
+fn most_common(input: &[Vec<bool>]) -> Vec<bool> {
+    let width = input.iter().map(|line| line.len()).max().unwrap();
+    // Get the count for each column
+    let mut count: Vec<usize> = vec![0; width];
+    for line in input {
+        for (i, bit) in line.iter().enumerate() {
+            count[i] += *bit as usize;
+        }
+    }
+    // Turn each count into a binary
+    let most_common: Vec<bool> = count
+        .iter()
+        .map(|&num| num as f32 >= input.len() as f32 / 2.0)
+        .collect();
+
+    most_common
+}
+
+fn bits_to_int(bits: &[bool]) -> i32 {
+    bits.iter()
+        .rev()
+        .fold((0, 0), |(num, pow), &bit| {
+            (num + 2_i32.pow(pow) * bit as i32, pow + 1)
+        })
+        .0
+}
+
+pub fn gen(input: &str) -> ParseResult<Vec<Vec<bool>>> {
+    let lines: Vec<_> = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    if lines.is_empty() {
+        return Err(ParseError::end(
+            input,
+            "expected at least one binary number",
+        ));
+    }
+    let width = lines[0].len();
+    // The ratings of the second part are only defined when every number is different
+    let mut seen = HashSet::new();
+
+    lines
+        .into_iter()
+        .map(|line| {
+            if line.len() != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected {} bits like the first line", width),
+                ));
+            }
+            if !seen.insert(line) {
+                return Err(ParseError::at(input, line, "number given twice"));
+            }
+            parse::chars(line)
+                .map(|(bit, at)| match bit {
+                    '0' => Ok(false),
+                    '1' => Ok(true),
+                    _ => Err(ParseError::at(input, at, "expected 0 or 1")),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn first(input: &[Vec<bool>]) -> i32 {
+    let most_common = most_common(input);
+    let epsilon = bits_to_int(&most_common);
+    let least_common: Vec<bool> = most_common.iter().map(|b| !b).collect();
+    let gamma = bits_to_int(&least_common);
+
+    epsilon * gamma
+}
+
+pub fn second(input: &[Vec<bool>]) -> i32 {
+    let mut oxygen = input.to_vec();
+    let mut i = 0;
+    while oxygen.len() > 1 {
+        let most_common = most_common(&oxygen);
+        oxygen.retain(|line| line[i] == most_common[i]);
+        i += 1;
+    }
+    let oxygen = bits_to_int(&oxygen.pop().unwrap());
+
+    let mut co2 = input.to_vec();
+    let mut i = 0;
+    while co2.len() > 1 {
+        let most_common = most_common(&co2);
+        co2.retain(|line| line[i] != most_common[i]);
+        i += 1;
+    }
+    let co2 = bits_to_int(&co2.pop().unwrap());
+
+    oxygen * co2
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Vec<Vec<bool>>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::examples::example;
+    use crate::Part;
+    #[test]
+    fn one() {
+        let input = super::gen(example(2021, 3, Part::One, "198")).unwrap();
+        assert_eq!(super::first(&input), 198)
+    }
+    #[test]
+    fn two() {
+        let input = super::gen(example(2021, 3, Part::Two, "230")).unwrap();
+        assert_eq!(super::second(&input), 230)
+    }
+    #[test]
+    fn bad_input() {
+        let err = super::gen("00100\n11210").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = super::gen("00100\n1111").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = super::gen("\n\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = super::gen("00\n01\n00").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
@@ -1,3 +1,6 @@
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
 use anyhow::{bail, Result};
 use core::ops;
 
@@ -92,36 +95,44 @@ impl ops::Index<(usize, usize)> for Board {
     }
 }
 
-pub fn gen(input: &str) -> (Vec<u32>, Vec<Board>) {
+pub fn gen(input: &str) -> ParseResult<(Vec<u32>, Vec<Board>)> {
     let mut elements = input.split("\n\n");
 
-    let numbers: Vec<u32> = elements
+    let numbers = elements
         .next()
         .unwrap()
+        .trim()
         .split(',')
-        .filter_map(|n| n.parse().ok())
-        .collect();
+        .map(|n| parse::number(input, n.trim()))
+        .collect::<ParseResult<Vec<u32>>>()?;
 
     let boards = elements
+        .filter(|board| !board.trim().is_empty())
         .map(|board| {
-            board
-                .split('\n')
+            let rows = board
+                .lines()
+                .filter(|line| !line.trim().is_empty())
                 .map(|line| {
                     line.split_whitespace()
-                        .filter_map(|s| s.parse().ok())
-                        .collect::<Vec<_>>()
+                        .map(|s| parse::number(input, s))
+                        .collect::<ParseResult<Vec<u32>>>()
                 })
-                .collect::<Vec<_>>()
+                .collect::<ParseResult<Vec<_>>>()?;
+            let width = rows[0].len();
+            let height = rows.len();
+            if rows.iter().any(|row| row.len() != width) {
+                return Err(ParseError::at(input, board, "board rows differ in length"));
+            }
+            let board_numbers: Vec<u32> = rows.into_iter().flatten().collect();
+            Board::new(board_numbers, width, height)
+                .map_err(|e| ParseError::at(input, board, e.to_string()))
         })
-        .fold(Vec::new(), |mut v, board| {
-            let width = board[0].len();
-            let height = width;
-            let board: Vec<u32> = board.into_iter().flatten().collect();
-            v.push(Board::new(board, width, height).unwrap());
-            v
-        });
-
-    (numbers, boards)
+        .collect::<ParseResult<Vec<_>>>()?;
+
+    if boards.is_empty() {
+        return Err(ParseError::end(input, "expected at least one board"));
+    }
+    Ok((numbers, boards))
 }
 
 pub fn first(input: &(Vec<u32>, Vec<Board>)) -> u32 {
@@ -154,32 +165,33 @@ pub fn second(input: &(Vec<u32>, Vec<Board>)) -> u32 {
     unreachable!()
 }
 
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = (Vec<u32>, Vec<Board>);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::examples::example;
+    use crate::Part;
 
     fn input() -> (Vec<u32>, Vec<Board>) {
-        gen(
-            "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-22 13 17 11  0
- 8  2 23  4 24
-21  9 14 16  7
- 6 10  3 18  5
- 1 12 20 15 19
-
- 3 15  0  2 22
- 9 18 13 17  5
-19  8  7 25 23
-20 11 10 24  4
-14 21 16 12  6
-
-14 21 17 24  4
-10 16 15  9 19
-18  8 23 26 20
-22 11 13  6  5
- 2  0 12  3  7",
-        )
+        gen(example(2021, 4, Part::One, "4512")).unwrap()
     }
 
     #[test]
@@ -192,6 +204,16 @@ mod test {
         assert_eq!(second(&input()), 1924);
     }
 
+    #[test]
+    fn bad_input() {
+        let err = gen("7,4,x\n\n1 2\n3 4").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+        let err = gen("7,4\n\n1 2\n3 4\n\n1 2\n3 -4").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (7, 3, "-4"));
+        let err = gen("7,4\n\n1 2\n3 4 5").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
     #[test]
     fn col_win() {
         let board = Board {
@@ -1,11 +1,17 @@
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
 use anyhow::{anyhow, Error};
+use once_cell::sync::Lazy;
 use regex::Regex;
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::{cmp, ops};
 
+static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d+),(\d+) -> (\d+),(\d+)$").unwrap());
+
 #[derive(Hash, PartialEq, Eq, Debug, Clone)]
-struct Point {
+pub struct Point {
     x: i64,
     y: i64,
 }
@@ -78,57 +84,77 @@ impl ops::Sub<&Point> for &Point {
     }
 }
 
-pub fn first(input: &str) -> u32 {
+pub fn gen(input: &str) -> ParseResult<Vec<(Point, Point)>> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            let cap = RE.captures(l).ok_or_else(|| {
+                ParseError::at(input, l, "expected a line like \"x1,y1 -> x2,y2\"")
+            })?;
+            let n = |i| parse::number(input, cap.get(i).unwrap().as_str());
+            Ok((Point::new(n(1)?, n(2)?), Point::new(n(3)?, n(4)?)))
+        })
+        .collect()
+}
+
+pub fn first(input: &[(Point, Point)]) -> u32 {
     let mut map = HashMap::new();
-    let re = Regex::new(r"(\d+,\d+) -> (\d+,\d+)").unwrap();
-    for cap in re.captures_iter(input) {
-        line(cap[1].parse().unwrap(), cap[2].parse().unwrap(), false)
-            .for_each(|p| *map.entry(p).or_insert(0) += 1);
+    for (p1, p2) in input {
+        line(p1.clone(), p2.clone(), false).for_each(|p| *map.entry(p).or_insert(0) += 1);
     }
     map.values().filter(|&&v| v >= 2).count() as u32
 }
 
-pub fn second(input: &str) -> u32 {
+pub fn second(input: &[(Point, Point)]) -> u32 {
     let mut map = HashMap::new();
-    let re = Regex::new(r"(\d+,\d+) -> (\d+,\d+)").unwrap();
-    for cap in re.captures_iter(input) {
-        line(cap[1].parse().unwrap(), cap[2].parse().unwrap(), true)
-            .for_each(|p| *map.entry(p).or_insert(0) += 1);
+    for (p1, p2) in input {
+        line(p1.clone(), p2.clone(), true).for_each(|p| *map.entry(p).or_insert(0) += 1);
     }
     map.values().filter(|&&v| v >= 2).count() as u32
 }
 
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Vec<(Point, Point)>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::examples::example;
+    use crate::Part;
 
     #[test]
     fn one() {
-        let input = "0,9 -> 5,9
-8,0 -> 0,8
-9,4 -> 3,4
-2,2 -> 2,1
-7,0 -> 7,4
-6,4 -> 2,0
-0,9 -> 2,9
-3,4 -> 1,4
-0,0 -> 8,8
-5,5 -> 8,2";
-        assert_eq!(first(input), 5);
+        let input = example(2021, 5, Part::One, "5");
+        assert_eq!(first(&gen(input).unwrap()), 5);
     }
 
     #[test]
     fn two() {
-        let input = "0,9 -> 5,9
-8,0 -> 0,8
-9,4 -> 3,4
-2,2 -> 2,1
-7,0 -> 7,4
-6,4 -> 2,0
-0,9 -> 2,9
-3,4 -> 1,4
-0,0 -> 8,8
-5,5 -> 8,2";
-        assert_eq!(second(input), 12);
+        let input = example(2021, 5, Part::Two, "12");
+        assert_eq!(second(&gen(input).unwrap()), 12);
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("0,9 -> 5,9\n8,0 => 0,8").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
     }
 }
@@ -0,0 +1,100 @@
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+
+type Num = u64;
+
+pub fn gen(input: &str) -> ParseResult<Vec<usize>> {
+    input
+        .trim()
+        .split(',')
+        .map(|n| {
+            let n = n.trim();
+            match parse::number(input, n)? {
+                timer @ 0..=8 => Ok(timer),
+                _ => Err(ParseError::at(input, n, "expected a timer between 0 and 8")),
+            }
+        })
+        .collect()
+}
+
+pub fn first(input: &[usize]) -> Num {
+    compute(80, input)
+}
+
+pub fn second(input: &[usize]) -> Num {
+    compute(256, input)
+}
+
+fn compute(days: usize, input: &[usize]) -> Num {
+    let mut fishes: [Num; 9] = input.iter().fold([0; 9], |mut arr, &n| {
+        arr[n] += 1;
+        arr
+    });
+
+    for _ in 0..days {
+        fishes[..=6].rotate_left(1);
+        let babbies = fishes[6];
+        fishes[6] += fishes[7];
+        fishes[7] = fishes[8];
+        fishes[8] = babbies;
+    }
+    fishes.iter().sum()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::examples::example;
+    use crate::Part;
+    fn input() -> &'static str {
+        example(2021, 6, Part::One, "5934")
+    }
+
+    #[test]
+    fn mini() {
+        let input = gen("2").unwrap();
+        assert_eq!(compute(5, &input), 2);
+    }
+
+    use super::*;
+    #[test]
+    fn one() {
+        assert_eq!(first(&gen(input()).unwrap()), 5934);
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(second(&gen(input()).unwrap()), 26984457539);
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("3,4,9,1").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+    }
+
+    #[test]
+    #[ignore]
+    fn bigboy_1() {
+        compute(9999999, &gen(input()).unwrap());
+    }
+}
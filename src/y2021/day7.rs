@@ -1,7 +1,12 @@
-pub fn gen(input: &str) -> Vec<i32> {
+use crate::parse::{self, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+
+pub fn gen(input: &str) -> ParseResult<Vec<i32>> {
     input
+        .trim()
         .split(',')
-        .map(|s| s.trim().parse().unwrap())
+        .map(|s| parse::number(input, s.trim()))
         .collect()
 }
 
@@ -25,11 +30,32 @@ pub fn second(v: &[i32]) -> i32 {
         .unwrap()
 }
 
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples::example;
+    use crate::Part;
     fn input() -> Vec<i32> {
-        gen("16,1,2,0,4,2,7,1,2,14")
+        gen(example(2021, 7, Part::One, "37")).unwrap()
     }
 
     #[test]
@@ -0,0 +1,193 @@
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::PartResult;
+use std::collections::HashSet;
+use std::iter;
+
+/// One line of notes: the ten unique signal patterns and the four output digits.
+#[derive(Debug, Clone)]
+pub struct Note {
+    patterns: Vec<HashSet<char>>,
+    output: Vec<HashSet<char>>,
+}
+
+fn segments(input: &str, words: &str, count: usize) -> ParseResult<Vec<HashSet<char>>> {
+    let words: Vec<_> = words.split_whitespace().collect();
+    if words.len() != count {
+        return Err(ParseError::at(
+            input,
+            words.first().copied().unwrap_or_default(),
+            format!("expected {} patterns, got {}", count, words.len()),
+        ));
+    }
+    words
+        .into_iter()
+        .map(|word| {
+            parse::chars(word)
+                .map(|(c, at)| match c {
+                    'a'..='g' => Ok(c),
+                    _ => Err(ParseError::at(
+                        input,
+                        at,
+                        "expected a segment between a and g",
+                    )),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn gen(input: &str) -> ParseResult<Vec<Note>> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (patterns, output) = line
+                .split_once('|')
+                .ok_or_else(|| ParseError::at(input, line, "expected a '|' separator"))?;
+            Ok(Note {
+                patterns: segments(input, patterns, 10)?,
+                output: segments(input, output, 4)?,
+            })
+        })
+        .collect()
+}
+
+pub fn first(input: &[Note]) -> u32 {
+    input
+        .iter()
+        .map(|note| {
+            note.output.iter().fold(0, |sum, word| {
+                sum + (word.len() == 2 || word.len() == 3 || word.len() == 4 || word.len() == 7)
+                    as u32
+            })
+        })
+        .sum()
+}
+
+fn decode_line(note: &Note) -> u32 {
+    let words = &note.patterns;
+    let one: &HashSet<char> = words.iter().find(|word| word.len() == 2).unwrap();
+    let seven: &HashSet<char> = words.iter().find(|word| word.len() == 3).unwrap();
+    let four: &HashSet<char> = words.iter().find(|word| word.len() == 4).unwrap();
+    let eight: &HashSet<char> = words.iter().find(|word| word.len() == 7).unwrap();
+    let four_seven: HashSet<char> = four.union(seven).copied().collect();
+    let nine: &HashSet<_> = words
+        .iter()
+        .find(|word| word.is_superset(&four_seven) && (*word - &four_seven).len() == 1)
+        .unwrap();
+    let epsilon = eight.iter().find(|c| !nine.contains(c)).unwrap();
+    let a: HashSet<_> = four_seven.iter().chain(iter::once(epsilon)).collect();
+    let eta = eight.iter().find(|c| !a.contains(c)).unwrap();
+    let seven_eta: HashSet<_> = seven.iter().chain(iter::once(eta)).copied().collect();
+    let three: &HashSet<_> = words
+        .iter()
+        .find(|word| word.iter().filter(|c| !seven_eta.contains(c)).count() == 1)
+        .unwrap();
+    let delta = three.difference(&seven_eta).next().unwrap();
+    let zero: HashSet<_> = {
+        let mut z = eight.clone();
+        z.remove(delta);
+        z
+    };
+    let five = words
+        .iter()
+        .filter(|word| word.is_subset(nine) && word.len() == 5)
+        .find(|&word| word != three)
+        .unwrap();
+    let six = {
+        let mut s = five.clone();
+        s.insert(*epsilon);
+        s
+    };
+    let mut so_far = vec![&zero, one, three, four, five, &six, seven, eight, nine];
+    let two = words
+        .iter()
+        .find(|word| !so_far.iter().any(|num| num == word))
+        .unwrap();
+
+    so_far.insert(2, two);
+
+    note.output
+        .iter()
+        .map(|word| {
+            so_far
+                .iter()
+                .position(|num| *num == word)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Cannot find {:#?} in {:#?} with input {:?}",
+                        word, so_far, note
+                    )
+                }) as u32
+        })
+        .rev()
+        .enumerate()
+        .fold(0, |acc, (pwr, digit)| {
+            acc + (digit * 10_u32.pow(pwr as u32))
+        })
+}
+
+pub fn second(input: &[Note]) -> u32 {
+    input.iter().map(decode_line).sum()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Vec<Note>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::example;
+    use crate::Part;
+    fn sample() -> &'static str {
+        example(2021, 8, Part::One, "26")
+    }
+
+    #[test]
+    fn mini() {
+        let input = gen(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf",
+        )
+        .unwrap();
+        assert_eq!(second(&input), 5353);
+    }
+
+    #[test]
+    fn one() {
+        assert_eq!(first(&gen(sample()).unwrap()), 26);
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(second(&gen(sample()).unwrap()), 61229);
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab cdfeb fcadb")
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = gen(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbah",
+        )
+        .unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 84, "h"));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::common::matrix::Matrix;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use crate::PartResult;
+use std::collections::HashSet;
+
+pub fn gen(input: &str) -> ParseResult<Matrix<u32>> {
+    Matrix::parse_grid(input, |c| c.to_digit(10).ok_or("expected a digit"))
+}
+
+fn basin_of(mat: &Matrix<u32>, x: usize, y: usize) -> HashSet<(usize, usize)> {
+    let mut basin = HashSet::new();
+    let mut to_visit = vec![(x, y)];
+
+    while let Some((x, y)) = to_visit.pop() {
+        for (xn, yn) in mat.rook_neighbor_indices(x, y) {
+            if mat[(xn, yn)] != 9 && basin.insert((xn, yn)) {
+                to_visit.push((xn, yn));
+            }
+        }
+    }
+    basin
+}
+
+pub fn first(mat: &Matrix<u32>) -> u32 {
+    mat.local_minima().into_iter().map(|lp| mat[lp] + 1).sum()
+}
+
+pub fn second(mat: &Matrix<u32>) -> u32 {
+    let mut b = mat
+        .local_minima()
+        .iter()
+        .map(|lp| basin_of(mat, lp.0, lp.1))
+        .collect::<Vec<_>>();
+    b.sort_unstable_by_key(|basin| basin.len());
+    b.iter()
+        .rev()
+        .take(3)
+        .map(|basin| basin.len() as u32)
+        .product()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Matrix<u32>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        gen(input)
+    }
+
+    fn first(input: &Self::Input) -> PartResult {
+        Ok(first(input).into())
+    }
+
+    fn second(input: &Self::Input) -> PartResult {
+        Ok(second(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::example;
+    use crate::Part;
+    fn sample() -> Matrix<u32> {
+        gen(example(2021, 9, Part::One, "15")).unwrap()
+    }
+
+    #[test]
+    fn one() {
+        assert_eq!(first(&sample()), 15)
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(second(&sample()), 1134)
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = gen("219\n39x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = gen("219\n3987").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
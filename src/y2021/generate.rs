@@ -0,0 +1,704 @@
+use crate::generate::{Generator, Rng};
+use crate::ocr;
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Display;
+
+/// See [`crate::generate`].
+pub fn generator(day: u8) -> Option<Generator> {
+    let (size, unit, generate): (usize, &str, fn(&mut Rng, usize) -> String) = match day {
+        1 => (2000, "depths", day1),
+        2 => (1000, "commands", day2),
+        3 => (1000, "numbers", day3),
+        4 => (100, "boards", day4),
+        5 => (500, "lines", day5),
+        6 => (300, "fish", day6),
+        7 => (1000, "crabs", day7),
+        8 => (200, "notes", day8),
+        9 => (100, "rows", day9),
+        10 => (100, "lines", day10),
+        11 => (10, "rows", day11),
+        12 => (7, "small caves", day12),
+        13 => (8, "letters", day13),
+        14 => (20, "template elements", day14),
+        15 => (100, "rows", day15),
+        16 => (250, "packets", day16),
+        17 => (0, "", day17),
+        18 => (100, "numbers", day18),
+        20 => (100, "rows", day20),
+        21 => (0, "", day21),
+        22 => (420, "steps", day22),
+        23 => (0, "", day23),
+        24 => (0, "", day24),
+        25 => (137, "rows", day25),
+        _ => return None,
+    };
+    Some(Generator {
+        size,
+        unit,
+        generate,
+    })
+}
+
+fn lines<T: Display>(items: impl IntoIterator<Item = T>) -> String {
+    items
+        .into_iter()
+        .map(|item| format!("{}\n", item))
+        .collect()
+}
+
+fn join<T: Display>(items: impl IntoIterator<Item = T>, separator: &str) -> String {
+    let items: Vec<_> = items.into_iter().map(|item| item.to_string()).collect();
+    items.join(separator)
+}
+
+/// A square grid of `size` rows, each cell drawn by `cell`.
+fn grid(rng: &mut Rng, size: usize, mut cell: impl FnMut(&mut Rng) -> char) -> String {
+    lines((0..size).map(|_| (0..size).map(|_| cell(rng)).collect::<String>()))
+}
+
+fn day1(rng: &mut Rng, size: usize) -> String {
+    let mut depth = rng.range(100..=200);
+    lines((0..size).map(|_| {
+        depth = (depth + rng.range(-15..=25)).max(0);
+        depth
+    }))
+}
+
+fn day2(rng: &mut Rng, size: usize) -> String {
+    // The aim stays small enough for the answers to fit an i32 at the real size
+    let mut aim = 0;
+    lines((0..size).map(|_| {
+        let n = rng.range(1..=9);
+        if rng.chance(0.5) {
+            format!("forward {}", n)
+        } else if aim >= n && (aim + n > 300 || rng.chance(0.4)) {
+            aim -= n;
+            format!("up {}", n)
+        } else {
+            aim += n;
+            format!("down {}", n)
+        }
+    }))
+}
+
+/// Whether filtering `numbers` bit by bit, keeping those with the most common bit or the
+/// others, ends on exactly one number like the second part needs.
+fn rating_found(numbers: &[usize], width: usize, most_common: bool) -> bool {
+    let mut left = numbers.to_vec();
+    for i in (0..width).rev() {
+        if left.len() <= 1 {
+            break;
+        }
+        let ones = left.iter().filter(|&&n| n >> i & 1 == 1).count();
+        let common = 2 * ones >= left.len();
+        left.retain(|&n| (n >> i & 1 == 1) == (common == most_common));
+    }
+    left.len() == 1
+}
+
+fn day3(rng: &mut Rng, size: usize) -> String {
+    // Numbers are distinct, otherwise the ratings cannot be told apart
+    let size = size.max(1);
+    let width = (usize::BITS - size.saturating_sub(1).leading_zeros()).max(12) as usize;
+    let mut numbers: Vec<usize> = (0..1 << width).collect();
+    loop {
+        rng.shuffle(&mut numbers);
+        let chosen = &numbers[..size];
+        if rating_found(chosen, width, true) && rating_found(chosen, width, false) {
+            return lines(
+                chosen
+                    .iter()
+                    .map(|n| format!("{:0width$b}", n, width = width)),
+            );
+        }
+    }
+}
+
+fn day4(rng: &mut Rng, size: usize) -> String {
+    let mut numbers: Vec<u32> = (0..100).collect();
+    rng.shuffle(&mut numbers);
+    let mut drawn = [0; 100];
+    for (i, &n) in numbers.iter().enumerate() {
+        drawn[n as usize] = i;
+    }
+    let board = |rng: &mut Rng| {
+        let mut board = numbers.clone();
+        rng.shuffle(&mut board);
+        board.truncate(25);
+        board
+    };
+    // When a board is complete, the draw that completes it
+    let wins = |board: &[u32]| {
+        let line = |cells: &mut dyn Iterator<Item = usize>| {
+            cells.map(|i| drawn[board[i] as usize]).max().unwrap()
+        };
+        (0..5)
+            .flat_map(|i| {
+                [
+                    line(&mut (0..5).map(|j| i * 5 + j)),
+                    line(&mut (0..5).map(|j| j * 5 + i)),
+                ]
+            })
+            .min()
+            .unwrap()
+    };
+
+    let mut boards: Vec<Vec<u32>> = (0..size).map(|_| board(rng)).collect();
+    // Exactly one board has to win last
+    loop {
+        let last = boards.iter().map(|b| wins(b)).max().unwrap_or(0);
+        let mut winning_last = (0..boards.len()).filter(|&i| wins(&boards[i]) == last);
+        match (winning_last.next(), winning_last.next()) {
+            (Some(i), Some(_)) => boards[i] = board(rng),
+            _ => break,
+        }
+    }
+
+    let boards = boards.iter().map(|b| {
+        let rows = b
+            .chunks(5)
+            .map(|row| join(row.iter().map(|n| format!("{:2}", n)), " "));
+        lines(rows)
+    });
+    format!("{}\n\n{}", join(&numbers, ","), join(boards, "\n"))
+}
+
+fn day5(rng: &mut Rng, size: usize) -> String {
+    const MAX: i64 = 989;
+    lines((0..size).map(|_| {
+        let (x1, y1) = (rng.range(10..=MAX), rng.range(10..=MAX));
+        let (dx, dy) = *rng.pick(&[
+            (1, 0),
+            (-1, 0),
+            (0, 1),
+            (0, -1),
+            (1, 1),
+            (1, -1),
+            (-1, 1),
+            (-1, -1),
+        ]);
+        let room = |p: i64, d: i64| match d {
+            1 => MAX - p,
+            -1 => p - 10,
+            _ => i64::MAX,
+        };
+        let length = rng.range(0..=room(x1, dx).min(room(y1, dy)));
+        format!("{},{} -> {},{}", x1, y1, x1 + dx * length, y1 + dy * length)
+    }))
+}
+
+fn day6(rng: &mut Rng, size: usize) -> String {
+    join((0..size).map(|_| rng.range(1..=5)), ",") + "\n"
+}
+
+fn day7(rng: &mut Rng, size: usize) -> String {
+    // Most crabs are near the start, like in the real inputs
+    let crabs = (0..size).map(|_| {
+        let max = rng.range(0..=1900);
+        rng.range(0..=max)
+    });
+    join(crabs, ",") + "\n"
+}
+
+fn day8(rng: &mut Rng, size: usize) -> String {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+    lines((0..size).map(|_| {
+        let mut wires: Vec<char> = "abcdefg".chars().collect();
+        rng.shuffle(&mut wires);
+        let pattern = |rng: &mut Rng, digit: usize| {
+            let mut pattern: Vec<char> = DIGITS[digit]
+                .chars()
+                .map(|c| wires[c as usize - 'a' as usize])
+                .collect();
+            rng.shuffle(&mut pattern);
+            pattern.into_iter().collect::<String>()
+        };
+        let mut digits: Vec<usize> = (0..10).collect();
+        rng.shuffle(&mut digits);
+        let patterns: Vec<_> = digits.iter().map(|&d| pattern(rng, d)).collect();
+        let output: Vec<_> = (0..4)
+            .map(|_| {
+                let digit = rng.index(10);
+                pattern(rng, digit)
+            })
+            .collect();
+        format!("{} | {}", patterns.join(" "), output.join(" "))
+    }))
+}
+
+fn day9(rng: &mut Rng, size: usize) -> String {
+    grid(rng, size, |rng| char::from(b'0' + rng.range(0..=9) as u8))
+}
+
+fn day10(rng: &mut Rng, size: usize) -> String {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    // The middle score of the incomplete lines needs an odd number of them
+    let incomplete = (rng.index(size.max(1)) | 1).min(size);
+    let mut kinds: Vec<bool> = (0..size).map(|i| i < incomplete).collect();
+    rng.shuffle(&mut kinds);
+    lines(kinds.into_iter().map(|incomplete| {
+        let length = rng.range(90..=110) as usize;
+        let mut stack = Vec::new();
+        // The closing character expected before each one of the line, if any
+        let mut expected = Vec::new();
+        let mut line = Vec::new();
+        while line.len() < length || stack.is_empty() {
+            expected.push(stack.last().copied());
+            // Few enough chunks stay open for the completion scores to fit a u64
+            if stack.is_empty() || (stack.len() < 20 && rng.chance(0.55)) {
+                let (open, close) = *rng.pick(&PAIRS);
+                stack.push(close);
+                line.push(open);
+            } else {
+                line.push(stack.pop().unwrap());
+            }
+        }
+        if !incomplete {
+            // What comes after the first illegal character does not matter
+            let open: Vec<_> = (1..line.len()).filter(|&i| expected[i].is_some()).collect();
+            let at = *rng.pick(&open);
+            let wrong: Vec<_> = PAIRS
+                .iter()
+                .map(|p| p.1)
+                .filter(|&c| Some(c) != expected[at])
+                .collect();
+            line[at] = *rng.pick(&wrong);
+        }
+        line.into_iter().collect::<String>()
+    }))
+}
+
+/// Steps before all the octopuses of `energies` flash at once, if they do within `limit`.
+fn synchronised(mut energies: Vec<Vec<u8>>, limit: usize) -> Option<usize> {
+    let size = energies.len();
+    for step in 1..=limit {
+        let mut flashing: Vec<(usize, usize)> = Vec::new();
+        for (y, row) in energies.iter_mut().enumerate() {
+            for (x, energy) in row.iter_mut().enumerate() {
+                *energy += 1;
+                if *energy == 10 {
+                    flashing.push((x, y));
+                }
+            }
+        }
+        let mut flashes = 0;
+        while let Some((x, y)) = flashing.pop() {
+            flashes += 1;
+            let (xs, ys) = (
+                x.saturating_sub(1)..(x + 2).min(size),
+                y.saturating_sub(1)..(y + 2).min(size),
+            );
+            for (nx, ny) in ys.flat_map(|ny| xs.clone().map(move |nx| (nx, ny))) {
+                let energy = &mut energies[ny][nx];
+                *energy += 1;
+                if *energy == 10 {
+                    flashing.push((nx, ny));
+                }
+            }
+        }
+        for energy in energies.iter_mut().flatten() {
+            if *energy > 9 {
+                *energy = 0;
+            }
+        }
+        if flashes == size * size {
+            return Some(step);
+        }
+    }
+    None
+}
+
+fn day11(rng: &mut Rng, size: usize) -> String {
+    // Part two never ends for grids that do not synchronise, which random ones may not
+    loop {
+        let energies: Vec<Vec<u8>> = (0..size)
+            .map(|_| (0..size).map(|_| rng.range(0..=9) as u8).collect())
+            .collect();
+        if synchronised(energies.clone(), 1000).is_some() {
+            return lines(energies.iter().map(|row| join(row, "")));
+        }
+    }
+}
+
+fn day12(rng: &mut Rng, size: usize) -> String {
+    let mut names = HashSet::new();
+    let mut name = |rng: &mut Rng, big: bool| loop {
+        let base = if big { b'A' } else { b'a' };
+        let name: String = (0..2)
+            .map(|_| char::from(base + rng.index(26) as u8))
+            .collect();
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+    let mut caves = vec![("start".to_string(), false), ("end".to_string(), false)];
+    caves.extend((0..size).map(|_| (name(rng, false), false)));
+    caves.extend((0..size.div_ceil(3)).map(|_| (name(rng, true), true)));
+    rng.shuffle(&mut caves[2..]);
+
+    // Two big caves next to each other would give infinitely many paths
+    let mut links: Vec<(usize, usize)> = Vec::new();
+    let link = |links: &mut Vec<(usize, usize)>, a: usize, b: usize| {
+        let fine = a != b && !(caves[a].1 && caves[b].1);
+        let new = !links.contains(&(a, b)) && !links.contains(&(b, a));
+        if fine && new {
+            links.push((a, b));
+        }
+        fine
+    };
+    // Every cave can be reached
+    for a in 1..caves.len() {
+        while !link(&mut links, a, rng.index(a)) {}
+    }
+    for _ in 0..2 * size {
+        let (a, b) = (rng.index(caves.len()), rng.index(caves.len()));
+        link(&mut links, a, b);
+    }
+    lines(
+        links
+            .into_iter()
+            .map(|(a, b)| format!("{}-{}", caves[a].0, caves[b].0)),
+    )
+}
+
+fn day13(rng: &mut Rng, size: usize) -> String {
+    let mut dots = BTreeSet::new();
+    for letter in 0..size {
+        let (_, rows) = rng.pick(ocr::GLYPHS);
+        for (y, row) in rows.iter().enumerate() {
+            for (x, _) in row.char_indices().filter(|&(_, c)| c == '#') {
+                dots.insert((letter * 5 + x, y));
+            }
+        }
+    }
+
+    // Unfolded from the last fold to the first, each dot either staying or being mirrored, and
+    // sometimes both. The real inputs end with three vertical folds, and alternate before that
+    let (mut width, mut height) = (size * 5, 6);
+    let mut folds = Vec::new();
+    for i in 0..12 {
+        let (axis, at) = if i >= 3 && i % 2 == 1 {
+            ('x', &mut width)
+        } else {
+            ('y', &mut height)
+        };
+        let fold = *at;
+        *at = 2 * fold + 1;
+        folds.push((axis, fold));
+        dots = dots
+            .into_iter()
+            .flat_map(|(x, y)| {
+                let mirrored = match axis {
+                    'x' => (2 * fold - x, y),
+                    _ => (x, 2 * fold - y),
+                };
+                match rng.index(10) {
+                    0..=1 => vec![(x, y), mirrored],
+                    2..=5 => vec![(x, y)],
+                    _ => vec![mirrored],
+                }
+            })
+            .collect();
+    }
+
+    let mut dots: Vec<_> = dots.into_iter().collect();
+    rng.shuffle(&mut dots);
+    let dots = lines(dots.into_iter().map(|(x, y)| format!("{},{}", x, y)));
+    let folds = folds.into_iter().rev();
+    let folds = lines(folds.map(|(axis, at)| format!("fold along {}={}", axis, at)));
+    format!("{}\n{}", dots, folds)
+}
+
+fn day14(rng: &mut Rng, size: usize) -> String {
+    let mut letters: Vec<char> = ('A'..='Z').collect();
+    rng.shuffle(&mut letters);
+    letters.truncate(10);
+    let template: String = (0..size.max(2)).map(|_| *rng.pick(&letters)).collect();
+    let rules = letters
+        .iter()
+        .flat_map(|&a| letters.iter().map(move |&b| (a, b)));
+    let rules: Vec<_> = rules.collect();
+    let rules = rules
+        .into_iter()
+        .map(|(a, b)| format!("{}{} -> {}", a, b, rng.pick(&letters)));
+    format!("{}\n\n{}", template, lines(rules))
+}
+
+fn day15(rng: &mut Rng, size: usize) -> String {
+    grid(rng, size, |rng| char::from(b'0' + rng.range(1..=9) as u8))
+}
+
+enum Packet {
+    Literal(u64),
+    Operator(u8, Vec<Packet>),
+}
+
+/// A packet tree of about `budget` packets, with its value. Operators whose value would not fit
+/// the u128 the solver uses are turned into minimums.
+fn packet(rng: &mut Rng, budget: &mut usize) -> (Packet, u128) {
+    *budget = budget.saturating_sub(1);
+    if *budget == 0 || rng.chance(0.4) {
+        let bits = *rng.pick(&[4, 8, 12, 24, 36]);
+        let value = rng.range(0..=(1 << bits) - 1) as u64;
+        return (Packet::Literal(value), value as u128);
+    }
+    let mut type_id = *rng.pick(&[0, 1, 2, 3, 5, 6, 7]);
+    let count = if type_id >= 5 {
+        2
+    } else {
+        rng.range(1..=5) as usize
+    };
+    let (subs, values): (Vec<_>, Vec<_>) = (0..count).map(|_| packet(rng, budget)).unzip();
+    let value = match type_id {
+        0 => values.iter().try_fold(0u128, |a, &b| a.checked_add(b)),
+        1 => values.iter().try_fold(1u128, |a, &b| a.checked_mul(b)),
+        2 => values.iter().min().copied(),
+        3 => values.iter().max().copied(),
+        5 => Some((values[0] > values[1]) as u128),
+        6 => Some((values[0] < values[1]) as u128),
+        _ => Some((values[0] == values[1]) as u128),
+    };
+    let value = value.unwrap_or_else(|| {
+        type_id = 2;
+        *values.iter().min().unwrap()
+    });
+    (Packet::Operator(type_id, subs), value)
+}
+
+fn push_bits(bits: &mut Vec<bool>, n: u64, width: usize) {
+    bits.extend((0..width).rev().map(|i| (n >> i) & 1 == 1));
+}
+
+fn encode(rng: &mut Rng, packet: &Packet, bits: &mut Vec<bool>) {
+    push_bits(bits, rng.range(0..=7) as u64, 3);
+    match packet {
+        Packet::Literal(value) => {
+            push_bits(bits, 4, 3);
+            let groups = (64 - value.leading_zeros() as usize).max(1).div_ceil(4);
+            for i in (0..groups).rev() {
+                bits.push(i > 0);
+                push_bits(bits, (value >> (4 * i)) & 0xf, 4);
+            }
+        }
+        Packet::Operator(type_id, subs) => {
+            push_bits(bits, *type_id as u64, 3);
+            let mut inner = Vec::new();
+            subs.iter().for_each(|sub| encode(rng, sub, &mut inner));
+            if inner.len() < 1 << 15 && rng.chance(0.5) {
+                bits.push(false);
+                push_bits(bits, inner.len() as u64, 15);
+            } else {
+                bits.push(true);
+                push_bits(bits, subs.len() as u64, 11);
+            }
+            bits.extend(inner);
+        }
+    }
+}
+
+fn day16(rng: &mut Rng, size: usize) -> String {
+    let mut budget = size.max(1);
+    let (packet, _) = packet(rng, &mut budget);
+    let mut bits = Vec::new();
+    encode(rng, &packet, &mut bits);
+    let hex: String = bits
+        .chunks(4)
+        .map(|nibble| {
+            let n = nibble.iter().fold(0, |n, &bit| n << 1 | bit as u32);
+            // The last nibble is padded with zeros
+            let n = n << (4 - nibble.len());
+            char::from_digit(n, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+    hex + "\n"
+}
+
+fn day17(rng: &mut Rng, _size: usize) -> String {
+    let x_min = rng.range(20..=250);
+    let y_max = rng.range(-150..=-50);
+    format!(
+        "target area: x={}..{}, y={}..{}\n",
+        x_min,
+        x_min + rng.range(10..=50),
+        y_max - rng.range(10..=50),
+        y_max
+    )
+}
+
+/// A snailfish pair at `depth`, pairs nested in four others being exploded by the puzzle.
+fn snailfish(rng: &mut Rng, depth: usize) -> String {
+    let element = |rng: &mut Rng| {
+        if depth < 4 && rng.chance(0.6) {
+            snailfish(rng, depth + 1)
+        } else {
+            rng.range(0..=9).to_string()
+        }
+    };
+    let left = element(rng);
+    format!("[{},{}]", left, element(rng))
+}
+
+fn day18(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| snailfish(rng, 1)))
+}
+
+fn day20(rng: &mut Rng, size: usize) -> String {
+    let pixel = |rng: &mut Rng| if rng.chance(0.5) { '#' } else { '.' };
+    let mut algorithm: Vec<char> = (0..512).map(|_| pixel(rng)).collect();
+    // An infinite lit background has to go dark again
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+    let algorithm: String = algorithm.into_iter().collect();
+    format!("{}\n\n{}", algorithm, grid(rng, size, pixel))
+}
+
+fn day21(rng: &mut Rng, _size: usize) -> String {
+    lines(
+        (1..=2).map(|player| format!("Player {} starting position: {}", player, rng.range(1..=10))),
+    )
+}
+
+fn day22(rng: &mut Rng, size: usize) -> String {
+    // The first steps are in the initialization region, the others far outside of it
+    let small = size.min(20);
+    let mut steps = Vec::new();
+    for i in 0..size {
+        let on = i == 0 || i == small || rng.chance(0.5);
+        let range = |rng: &mut Rng| {
+            if i < small {
+                let start = rng.range(-50..=40);
+                (start, rng.range(start..=50))
+            } else {
+                let start = rng.range(-100_000..=90_000);
+                (start, start + rng.range(1000..=30_000))
+            }
+        };
+        let mut x = range(rng);
+        while i >= small && (-50..=50).contains(&x.0) {
+            x = range(rng);
+        }
+        let (y, z) = (range(rng), range(rng));
+        steps.push(format!(
+            "{} x={}..{},y={}..{},z={}..{}",
+            if on { "on" } else { "off" },
+            x.0,
+            x.1,
+            y.0,
+            y.1,
+            z.0,
+            z.1
+        ));
+    }
+    lines(steps)
+}
+
+fn day23(rng: &mut Rng, _size: usize) -> String {
+    let mut amphipods: Vec<char> = "AABBCCDD".chars().collect();
+    rng.shuffle(&mut amphipods);
+    let row = |row: &[char]| join(row, "#");
+    format!(
+        "#############\n#...........#\n###{}###\n  #{}#\n  #########\n",
+        row(&amphipods[..4]),
+        row(&amphipods[4..])
+    )
+}
+
+/// The 18 instructions handling one digit of a model number.
+fn monad_block(divide: i64, check: i64, offset: i64) -> String {
+    format!(
+        "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+         mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\n\
+         add z y\n",
+        divide, check, offset
+    )
+}
+
+fn day24(rng: &mut Rng, _size: usize) -> String {
+    // Seven digits push onto a base 26 stack and seven pop, a pair being valid when the popping
+    // digit is the pushing one plus an offset between -8 and 8
+    let mut pushes: Vec<bool> = Vec::new();
+    let mut depth = 0;
+    while pushes.len() < 14 {
+        let push = depth == 0 || (depth < 14 - pushes.len() && rng.chance(0.5));
+        if push {
+            depth += 1;
+        } else {
+            depth -= 1;
+        }
+        pushes.push(push);
+    }
+    let mut offsets = Vec::new();
+    let mut program = String::new();
+    for push in pushes {
+        if push {
+            let offset = rng.range(1..=15);
+            offsets.push(offset);
+            program += &monad_block(1, rng.range(10..=16), offset);
+        } else {
+            let pushed = offsets.pop().unwrap();
+            let difference = rng.range((pushed - 16).max(-8)..=pushed.min(8));
+            program += &monad_block(26, difference - pushed, rng.range(1..=16));
+        }
+    }
+    program
+}
+
+fn day25(rng: &mut Rng, size: usize) -> String {
+    let cell = |rng: &mut Rng| match rng.index(10) {
+        0..=2 => '>',
+        3..=5 => 'v',
+        _ => '.',
+    };
+    lines((0..size).map(|_| (0..size + 2).map(|_| cell(rng)).collect::<String>()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Part, PartError};
+
+    #[test]
+    fn solvable() {
+        // Days 23 and 24 take seconds to solve in debug builds
+        let slow = [23, 24];
+        for solution in super::super::SOLUTIONS {
+            let day = solution.day();
+            let generator = generator(day).unwrap();
+            // Small inputs too, to catch generators assuming the real size. Only those are
+            // solved, as the solvers may still choke on inputs that parse
+            let small = generator.size / 4 + 1;
+            for size in [generator.size, small] {
+                let name = format!("day {} of size {}", day, size);
+                let input = (generator.generate)(&mut Rng::new(3), size);
+                let again = (generator.generate)(&mut Rng::new(3), size);
+                assert!(input == again, "{} differs from one run to the next", name);
+                let parsed = match solution.parse(&input) {
+                    Ok(parsed) => parsed,
+                    Err(e) => panic!("{}: {}\n{}", name, e, input),
+                };
+                if size != small || slow.contains(&day) {
+                    continue;
+                }
+                for part in Part::BOTH {
+                    let answer = match parsed.solve(part) {
+                        Ok(answer) => answer,
+                        Err(PartError::NotImplemented) => continue,
+                        Err(e) => panic!("{} part {}: {}", name, part, e),
+                    };
+                    if day == 13 && part == Part::Two {
+                        let letters = answer.to_string();
+                        assert_eq!(letters.len(), size, "{}", letters);
+                        assert!(
+                            letters.chars().all(|c| c.is_ascii_uppercase()),
+                            "{}",
+                            letters
+                        );
+                    }
+                }
+            }
+        }
+    }
+}